@@ -1,6 +1,9 @@
-use std::collections::{HashMap, HashSet, VecDeque};
-use std::fmt::Debug;
+use crate::heap::{Heap, Order};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt::{Debug, Display};
 use std::hash::Hash;
+use std::iter::Peekable;
+use std::str::{Chars, FromStr};
 
 #[derive(Clone, Debug)]
 pub enum Kind {
@@ -32,10 +35,17 @@ pub enum GraphError {
     VertexAlreadyExists,
     VertexInexistant,
     EdgeAlreadyExists,
+    EdgeInexistant,
     EdgeTypeMismatch,
+    KindMismatch,
     NoSuchRoute,
+    NegativeCycle,
+    CycleDetected,
+    ParseError(String),
 }
 
+type ShortestPathTree<K> = (HashMap<K, isize>, HashMap<K, K>);
+
 #[derive(Eq, Hash, PartialEq, Debug)]
 pub struct Path<K: Eq + Clone + Hash + Debug> {
     from: K,
@@ -98,6 +108,58 @@ where
         self.add_edge(from, to, EdgeType::Unweighted)
     }
 
+    pub fn remove_vertex(&mut self, id: K) -> Result<Vertex<K, V>, GraphError> {
+        let vertex = self
+            .vertices
+            .remove(&id)
+            .ok_or(GraphError::VertexInexistant)?;
+        self.edges.remove(&id);
+        for adjacents in self.edges.values_mut() {
+            adjacents.remove(&id);
+        }
+        Ok(vertex)
+    }
+
+    pub fn remove_edge(&mut self, from: K, to: K) -> Result<EdgeType, GraphError> {
+        self.get_vertex(from.clone())
+            .ok_or(GraphError::VertexInexistant)?;
+        self.get_vertex(to.clone())
+            .ok_or(GraphError::VertexInexistant)?;
+        let edge_type = self
+            .edges
+            .get_mut(&from)
+            .and_then(|adjacents| adjacents.remove(&to))
+            .ok_or(GraphError::EdgeInexistant)?;
+        if let Kind::Undirected = self.kind {
+            if let Some(adjacents) = self.edges.get_mut(&to) {
+                adjacents.remove(&from);
+            }
+        }
+        Ok(edge_type)
+    }
+
+    pub fn update_weight(&mut self, from: K, to: K, weight: isize) -> Result<(), GraphError> {
+        if let EdgeType::Unweighted = self.edge_type {
+            return Err(GraphError::EdgeTypeMismatch);
+        }
+        self.get_vertex(from.clone())
+            .ok_or(GraphError::VertexInexistant)?;
+        self.get_vertex(to.clone())
+            .ok_or(GraphError::VertexInexistant)?;
+        let edge_type = self
+            .edges
+            .get_mut(&from)
+            .and_then(|adjacents| adjacents.get_mut(&to))
+            .ok_or(GraphError::EdgeInexistant)?;
+        *edge_type = EdgeType::Weighted(weight);
+        if let Kind::Undirected = self.kind {
+            if let Some(edge_type) = self.edges.get_mut(&to).and_then(|a| a.get_mut(&from)) {
+                *edge_type = EdgeType::Weighted(weight);
+            }
+        }
+        Ok(())
+    }
+
     pub fn get_edges(&self, from: K) -> Result<HashMap<K, EdgeType>, GraphError> {
         self.edges
             .get(&from)
@@ -141,76 +203,124 @@ where
     }
 
     pub fn find_shortest_paths(&self, from: K) -> Result<HashSet<Path<K>>, GraphError> {
+        let (distances, parents) = self.shortest_path_tree(&from)?;
         let mut shortest_paths: HashSet<Path<K>> = HashSet::new();
         for to in self.vertices.keys() {
-            let (path, cost) = self.find_shortest_path(&from, to)?;
+            let distance = distances.get(to).ok_or(GraphError::NoSuchRoute)?;
             shortest_paths.insert(Path {
                 from: from.clone(),
                 to: to.clone(),
-                path,
-                distance: cost,
+                path: Self::backtrack(&from, to, &parents),
+                distance: *distance,
             });
         }
         Ok(shortest_paths)
     }
 
-    fn find_shortest_path(&self, from: &K, to: &K) -> Result<(Vec<K>, isize), GraphError> {
-        let mut weights: HashMap<K, isize> = HashMap::from([(from.clone(), 0)]);
+    pub fn find_shortest_path(&self, from: &K, to: &K) -> Result<(Vec<K>, isize), GraphError> {
+        self.get_vertex(to.clone())
+            .ok_or(GraphError::VertexInexistant)?;
+        let (distances, parents) = self.shortest_path_tree(from)?;
+        let distance = distances.get(to).ok_or(GraphError::NoSuchRoute)?;
+        Ok((Self::backtrack(from, to, &parents), *distance))
+    }
+
+    fn shortest_path_tree(&self, from: &K) -> Result<ShortestPathTree<K>, GraphError> {
+        if !self.vertices.contains_key(from) {
+            return Err(GraphError::VertexInexistant);
+        }
+        let negative = self
+            .edges
+            .values()
+            .flat_map(|adjacents| adjacents.values())
+            .any(|et| Self::weight(et) < 0);
+        if negative {
+            self.bellman_ford(from)
+        } else {
+            Ok(self.dijkstra(from))
+        }
+    }
+
+    fn dijkstra(&self, from: &K) -> ShortestPathTree<K> {
+        let vertices: Vec<&K> = self.vertices.keys().collect();
+        let indices: HashMap<&K, usize> =
+            vertices.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+        let mut distances: HashMap<K, isize> = HashMap::from([(from.clone(), 0)]);
         let mut parents: HashMap<K, K> = HashMap::new();
-        let mut visited: HashSet<K> = HashSet::new();
-        let vertices: HashSet<K> = self.vertices.keys().cloned().collect();
-        while visited.len() < vertices.len() {
-            let mut candidates = weights.clone().into_iter().collect::<Vec<(K, isize)>>();
-            candidates.sort_by_key(|(_, w)| *w);
-            let (current, start_weight) =
-                match candidates.into_iter().find(|(v, _)| !visited.contains(v)) {
-                    Some((v, w)) => (v, w),
-                    None => break,
+        let mut visited: HashSet<&K> = HashSet::new();
+        let mut queue = Heap::new(Order::Min);
+        queue.insert(indices[from], 0);
+        while let Some(index) = queue.delete() {
+            let current = vertices[index];
+            // a vertex is queued once per improvement, only its first pop counts
+            if !visited.insert(current) {
+                continue;
+            }
+            let start_weight = distances[current];
+            for (adjacent, et) in &self.edges[current] {
+                let new_weight = start_weight + Self::weight(et);
+                if distances.get(adjacent).is_none_or(|w| new_weight < *w) {
+                    distances.insert(adjacent.clone(), new_weight);
+                    parents.insert(adjacent.clone(), current.clone());
+                    queue.insert(indices[adjacent], new_weight);
+                }
+            }
+        }
+        (distances, parents)
+    }
+
+    fn bellman_ford(&self, from: &K) -> Result<ShortestPathTree<K>, GraphError> {
+        let mut distances: HashMap<K, isize> = HashMap::from([(from.clone(), 0)]);
+        let mut parents: HashMap<K, K> = HashMap::new();
+        for _ in 1..self.vertices.len() {
+            let mut relaxed = false;
+            for (current, adjacents) in &self.edges {
+                let Some(start_weight) = distances.get(current).copied() else {
+                    continue;
                 };
-            let adjacents = self.get_edges(current.clone())?;
+                for (adjacent, et) in adjacents {
+                    let new_weight = start_weight + Self::weight(et);
+                    if distances.get(adjacent).is_none_or(|w| new_weight < *w) {
+                        distances.insert(adjacent.clone(), new_weight);
+                        parents.insert(adjacent.clone(), current.clone());
+                        relaxed = true;
+                    }
+                }
+            }
+            if !relaxed {
+                return Ok((distances, parents));
+            }
+        }
+        for (current, adjacents) in &self.edges {
+            let Some(start_weight) = distances.get(current) else {
+                continue;
+            };
             for (adjacent, et) in adjacents {
-                let edge_weight = match et {
-                    EdgeType::Weighted(w) => w,
-                    EdgeType::Unweighted => 1,
-                };
-                let new_weight = start_weight + edge_weight;
-                let insert = if let Some(weight) = weights.get(&adjacent) {
-                    new_weight < *weight
-                } else {
-                    true
-                };
-                if insert {
-                    weights.insert(adjacent.clone(), new_weight);
-                    parents.insert(adjacent.clone(), current.clone());
+                if start_weight + Self::weight(et) < distances[adjacent] {
+                    return Err(GraphError::NegativeCycle);
                 }
             }
-            visited.insert(current.clone());
         }
-        let path = Self::backtrack(
-            from,
-            to,
-            &parents
-                .into_iter()
-                .map(|(a, b)| (b, a))
-                .collect::<Vec<(K, K)>>(),
-        );
-        let weight = weights.get(to).ok_or(GraphError::NoSuchRoute)?;
-        Ok((path, *weight))
+        Ok((distances, parents))
     }
 
-    fn backtrack(start: &K, finish: &K, successors: &[(K, K)]) -> Vec<K> {
-        let mut path: Vec<K> = Vec::new();
-        let mut current = finish.clone();
-        loop {
-            path.push(current.clone());
-            if let Some((next, _)) = successors.iter().find(|(_, c)| *c == current) {
-                if next == start {
-                    path.push(next.clone());
-                    break;
+    fn weight(edge_type: &EdgeType) -> isize {
+        match edge_type {
+            EdgeType::Weighted(w) => *w,
+            EdgeType::Unweighted => 1,
+        }
+    }
+
+    fn backtrack(start: &K, finish: &K, parents: &HashMap<K, K>) -> Vec<K> {
+        let mut path: Vec<K> = vec![finish.clone()];
+        let mut current = finish;
+        while current != start {
+            match parents.get(current) {
+                Some(parent) => {
+                    path.push(parent.clone());
+                    current = parent;
                 }
-                current = next.clone();
-            } else {
-                break;
+                None => break,
             }
         }
         path.reverse();
@@ -273,6 +383,723 @@ where
     }
 }
 
+impl<K, V> Graph<K, V>
+where
+    K: Eq + Clone + Hash + Debug + Ord,
+    V: Clone,
+{
+    pub fn topological_sort(&self) -> Result<Vec<K>, GraphError> {
+        self.require_directed()?;
+        let mut in_degrees: HashMap<&K, usize> = self.vertices.keys().map(|k| (k, 0)).collect();
+        for adjacent in self.edges.values().flat_map(|adjacents| adjacents.keys()) {
+            *in_degrees.entry(adjacent).or_default() += 1;
+        }
+        // the smallest ready vertex goes first, so equal graphs always sort alike
+        let mut ready: BTreeSet<&K> = in_degrees
+            .iter()
+            .filter(|(_, d)| **d == 0)
+            .map(|(k, _)| *k)
+            .collect();
+        let mut sorted: Vec<K> = Vec::new();
+        while let Some(current) = ready.pop_first() {
+            sorted.push(current.clone());
+            for adjacent in self.edges[current].keys() {
+                let in_degree = in_degrees.entry(adjacent).or_default();
+                *in_degree -= 1;
+                if *in_degree == 0 {
+                    ready.insert(adjacent);
+                }
+            }
+        }
+        if sorted.len() < self.vertices.len() {
+            return Err(GraphError::CycleDetected);
+        }
+        Ok(sorted)
+    }
+
+    pub fn find_cycle(&self) -> Result<Option<Vec<K>>, GraphError> {
+        self.require_directed()?;
+        let mut finished: HashSet<K> = HashSet::new();
+        let mut path: Vec<K> = Vec::new();
+        for vertex in self.sorted_vertices() {
+            if finished.contains(&vertex) {
+                continue;
+            }
+            if let Some(cycle) = self.do_find_cycle(vertex, &mut path, &mut finished) {
+                return Ok(Some(cycle));
+            }
+        }
+        Ok(None)
+    }
+
+    pub fn strongly_connected_components(&self) -> Result<Vec<Vec<K>>, GraphError> {
+        self.require_directed()?;
+        let mut tarjan = Tarjan {
+            index: 0,
+            indices: HashMap::new(),
+            lowlinks: HashMap::new(),
+            stack: Vec::new(),
+            on_stack: HashSet::new(),
+            components: Vec::new(),
+        };
+        for vertex in self.sorted_vertices() {
+            if !tarjan.indices.contains_key(&vertex) {
+                self.strong_connect(vertex, &mut tarjan);
+            }
+        }
+        Ok(tarjan.components)
+    }
+
+    pub fn minimum_spanning_tree_kruskal(&self) -> Result<Self, GraphError> {
+        self.require_undirected()?;
+        let mut edges: Vec<(isize, K, K)> = Vec::new();
+        for (from, adjacents) in &self.edges {
+            for (to, et) in adjacents.iter().filter(|(to, _)| from < *to) {
+                edges.push((Self::weight(et), from.clone(), to.clone()));
+            }
+        }
+        edges.sort();
+        let mut roots: HashMap<K, K> = HashMap::new();
+        let mut tree = self.without_edges();
+        for (_, from, to) in edges {
+            let (a, b) = (Self::root(&mut roots, &from), Self::root(&mut roots, &to));
+            if a != b {
+                roots.insert(a, b);
+                let et = self.edges[&from][&to].clone();
+                tree.add_edge(from, to, et)?;
+            }
+        }
+        Ok(tree)
+    }
+
+    pub fn minimum_spanning_tree_prim(&self) -> Result<Self, GraphError> {
+        self.require_undirected()?;
+        let vertices = self.sorted_vertices();
+        let indices: HashMap<&K, usize> =
+            vertices.iter().enumerate().map(|(i, v)| (v, i)).collect();
+        let mut visited: HashSet<usize> = HashSet::new();
+        let mut queue = Heap::new(Order::Min);
+        let mut tree = self.without_edges();
+        let enqueue = |queue: &mut Heap<(usize, usize)>, from: usize| {
+            for (to, et) in &self.edges[&vertices[from]] {
+                queue.insert((from, indices[to]), Self::weight(et));
+            }
+        };
+        // restarting from every unvisited vertex yields a spanning forest
+        for start in 0..vertices.len() {
+            if !visited.insert(start) {
+                continue;
+            }
+            enqueue(&mut queue, start);
+            while let Some((from, to)) = queue.delete() {
+                if !visited.insert(to) {
+                    continue;
+                }
+                let et = self.edges[&vertices[from]][&vertices[to]].clone();
+                tree.add_edge(vertices[from].clone(), vertices[to].clone(), et)?;
+                enqueue(&mut queue, to);
+            }
+        }
+        Ok(tree)
+    }
+
+    pub fn connected_components(&self) -> Result<Vec<Vec<K>>, GraphError> {
+        self.require_undirected()?;
+        let mut visited: HashSet<K> = HashSet::new();
+        let mut components: Vec<Vec<K>> = Vec::new();
+        for vertex in self.sorted_vertices() {
+            if !visited.insert(vertex.clone()) {
+                continue;
+            }
+            let mut component: Vec<K> = Vec::new();
+            let mut worklist = VecDeque::from([vertex]);
+            while let Some(current) = worklist.pop_front() {
+                for adjacent in self.edges[&current].keys() {
+                    if visited.insert(adjacent.clone()) {
+                        worklist.push_back(adjacent.clone());
+                    }
+                }
+                component.push(current);
+            }
+            component.sort();
+            components.push(component);
+        }
+        Ok(components)
+    }
+
+    fn do_find_cycle(
+        &self,
+        vertex: K,
+        path: &mut Vec<K>,
+        finished: &mut HashSet<K>,
+    ) -> Option<Vec<K>> {
+        path.push(vertex.clone());
+        for adjacent in self.sorted_adjacents(&vertex) {
+            if let Some(start) = path.iter().position(|v| *v == adjacent) {
+                let mut cycle = path[start..].to_vec();
+                cycle.push(adjacent);
+                return Some(cycle);
+            }
+            if finished.contains(&adjacent) {
+                continue;
+            }
+            if let Some(cycle) = self.do_find_cycle(adjacent, path, finished) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        finished.insert(vertex);
+        None
+    }
+
+    fn strong_connect(&self, vertex: K, tarjan: &mut Tarjan<K>) {
+        tarjan.indices.insert(vertex.clone(), tarjan.index);
+        tarjan.lowlinks.insert(vertex.clone(), tarjan.index);
+        tarjan.index += 1;
+        tarjan.stack.push(vertex.clone());
+        tarjan.on_stack.insert(vertex.clone());
+        for adjacent in self.sorted_adjacents(&vertex) {
+            if !tarjan.indices.contains_key(&adjacent) {
+                self.strong_connect(adjacent.clone(), tarjan);
+                let lowlink = tarjan.lowlinks[&vertex].min(tarjan.lowlinks[&adjacent]);
+                tarjan.lowlinks.insert(vertex.clone(), lowlink);
+            } else if tarjan.on_stack.contains(&adjacent) {
+                let lowlink = tarjan.lowlinks[&vertex].min(tarjan.indices[&adjacent]);
+                tarjan.lowlinks.insert(vertex.clone(), lowlink);
+            }
+        }
+        if tarjan.lowlinks[&vertex] == tarjan.indices[&vertex] {
+            let mut component: Vec<K> = Vec::new();
+            while let Some(member) = tarjan.stack.pop() {
+                tarjan.on_stack.remove(&member);
+                let done = member == vertex;
+                component.push(member);
+                if done {
+                    break;
+                }
+            }
+            component.sort();
+            tarjan.components.push(component);
+        }
+    }
+
+    fn root(roots: &mut HashMap<K, K>, vertex: &K) -> K {
+        let mut root = vertex.clone();
+        while let Some(parent) = roots.get(&root) {
+            root = parent.clone();
+        }
+        if root != *vertex {
+            roots.insert(vertex.clone(), root.clone());
+        }
+        root
+    }
+
+    fn without_edges(&self) -> Self {
+        Graph {
+            kind: self.kind.clone(),
+            edge_type: self.edge_type.clone(),
+            vertices: self.vertices.clone(),
+            edges: self
+                .vertices
+                .keys()
+                .map(|k| (k.clone(), HashMap::new()))
+                .collect(),
+        }
+    }
+
+    fn sorted_vertices(&self) -> Vec<K> {
+        let mut vertices: Vec<K> = self.vertices.keys().cloned().collect();
+        vertices.sort();
+        vertices
+    }
+
+    fn sorted_adjacents(&self, from: &K) -> Vec<K> {
+        let mut adjacents: Vec<K> = self.edges[from].keys().cloned().collect();
+        adjacents.sort();
+        adjacents
+    }
+
+    fn require_directed(&self) -> Result<(), GraphError> {
+        match self.kind {
+            Kind::Directed => Ok(()),
+            Kind::Undirected => Err(GraphError::KindMismatch),
+        }
+    }
+
+    fn require_undirected(&self) -> Result<(), GraphError> {
+        match self.kind {
+            Kind::Directed => Err(GraphError::KindMismatch),
+            Kind::Undirected => Ok(()),
+        }
+    }
+}
+
+struct Tarjan<K> {
+    index: usize,
+    indices: HashMap<K, usize>,
+    lowlinks: HashMap<K, usize>,
+    stack: Vec<K>,
+    on_stack: HashSet<K>,
+    components: Vec<Vec<K>>,
+}
+
+impl<K, V> Graph<K, V>
+where
+    K: Eq + Clone + Hash + Debug + Ord + Display,
+    V: Clone + Display,
+{
+    pub fn to_dot(&self) -> String {
+        let (keyword, connector) = match self.kind {
+            Kind::Directed => ("digraph", "->"),
+            Kind::Undirected => ("graph", "--"),
+        };
+        let mut dot = format!("{keyword} {{\n");
+        for id in self.sorted_vertices() {
+            let value = &self.vertices[&id].value;
+            dot.push_str(&format!(
+                "    \"{}\" [label=\"{}\"];\n",
+                escape(&id.to_string()),
+                escape(&value.to_string())
+            ));
+        }
+        for (from, to, edge_type) in self.sorted_edges() {
+            let (from, to) = (escape(&from.to_string()), escape(&to.to_string()));
+            match edge_type {
+                EdgeType::Weighted(w) => dot.push_str(&format!(
+                    "    \"{from}\" {connector} \"{to}\" [label=\"{w}\"];\n"
+                )),
+                EdgeType::Unweighted => {
+                    dot.push_str(&format!("    \"{from}\" {connector} \"{to}\";\n"))
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    pub fn to_edge_list(&self) -> String {
+        let mut list = format!("{} {}\n", self.kind_name(), self.edge_type_name());
+        for id in self.sorted_vertices() {
+            let value = quote(&self.vertices[&id].value.to_string());
+            list.push_str(&format!("vertex {} {value}\n", quote(&id.to_string())));
+        }
+        for (from, to, edge_type) in self.sorted_edges() {
+            let (from, to) = (quote(&from.to_string()), quote(&to.to_string()));
+            match edge_type {
+                EdgeType::Weighted(w) => list.push_str(&format!("edge {from} {to} {w}\n")),
+                EdgeType::Unweighted => list.push_str(&format!("edge {from} {to}\n")),
+            }
+        }
+        list
+    }
+
+    pub fn to_json(&self) -> String {
+        let vertices: Vec<String> = self
+            .sorted_vertices()
+            .iter()
+            .map(|id| {
+                format!(
+                    "\"{}\":\"{}\"",
+                    escape(&id.to_string()),
+                    escape(&self.vertices[id].value.to_string())
+                )
+            })
+            .collect();
+        let edges: Vec<String> = self
+            .sorted_vertices()
+            .iter()
+            .map(|from| {
+                let adjacents = self.sorted_adjacents(from).into_iter().map(|to| {
+                    let to_json = escape(&to.to_string());
+                    match &self.edges[from][&to] {
+                        EdgeType::Weighted(w) => format!("\"{to_json}\":{w}"),
+                        EdgeType::Unweighted => format!("\"{to_json}\""),
+                    }
+                });
+                let adjacents: Vec<String> = adjacents.collect();
+                match self.edge_type {
+                    EdgeType::Weighted(_) => format!(
+                        "\"{}\":{{{}}}",
+                        escape(&from.to_string()),
+                        adjacents.join(",")
+                    ),
+                    EdgeType::Unweighted => format!(
+                        "\"{}\":[{}]",
+                        escape(&from.to_string()),
+                        adjacents.join(",")
+                    ),
+                }
+            })
+            .collect();
+        format!(
+            "{{\"kind\":\"{}\",\"weighted\":{},\"vertices\":{{{}}},\"edges\":{{{}}}}}\n",
+            self.kind_name(),
+            matches!(self.edge_type, EdgeType::Weighted(_)),
+            vertices.join(","),
+            edges.join(",")
+        )
+    }
+
+    fn kind_name(&self) -> &'static str {
+        match self.kind {
+            Kind::Directed => "directed",
+            Kind::Undirected => "undirected",
+        }
+    }
+
+    fn edge_type_name(&self) -> &'static str {
+        match self.edge_type {
+            EdgeType::Weighted(_) => "weighted",
+            EdgeType::Unweighted => "unweighted",
+        }
+    }
+
+    /// Undirected edges are listed once, from the smaller to the greater vertex.
+    fn sorted_edges(&self) -> Vec<(K, K, EdgeType)> {
+        let mut edges: Vec<(K, K, EdgeType)> = Vec::new();
+        for from in self.sorted_vertices() {
+            for to in self.sorted_adjacents(&from) {
+                if let Kind::Undirected = self.kind {
+                    if to < from {
+                        continue;
+                    }
+                }
+                let edge_type = self.edges[&from][&to].clone();
+                edges.push((from.clone(), to, edge_type));
+            }
+        }
+        edges
+    }
+}
+
+impl<K, V> Graph<K, V>
+where
+    K: Eq + Clone + Hash + Debug + FromStr,
+    V: Clone + FromStr,
+{
+    pub fn from_edge_list(text: &str) -> Result<Self, GraphError> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(i, l)| (i + 1, l.trim()))
+            .filter(|(_, l)| !l.is_empty() && !l.starts_with('#'));
+        let (number, header) = lines
+            .next()
+            .ok_or(GraphError::ParseError(String::from("missing header line")))?;
+        let mut graph = match header.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            [kind, "weighted"] => Graph::new_weighted(Self::parse_kind(kind)?),
+            [kind, "unweighted"] => Graph::new_unweighted(Self::parse_kind(kind)?),
+            _ => {
+                return Err(GraphError::ParseError(format!(
+                    "line {number}: expected '<directed|undirected> <weighted|unweighted>', got '{header}'"
+                )))
+            }
+        };
+        for (number, line) in lines {
+            let error = |reason: &str| GraphError::ParseError(format!("line {number}: {reason}"));
+            let fields = JsonParser::words(line)?;
+            let fields: Vec<&str> = fields.iter().map(String::as_str).collect();
+            match fields.as_slice() {
+                ["vertex", id, value] => {
+                    let id = id.parse::<K>().map_err(|_| error("invalid vertex id"))?;
+                    let value = value
+                        .parse::<V>()
+                        .map_err(|_| error("invalid vertex value"))?;
+                    graph.add_vertex(id, value)?;
+                }
+                ["edge", from, to, rest @ ..] => {
+                    let from = from.parse::<K>().map_err(|_| error("invalid vertex id"))?;
+                    let to = to.parse::<K>().map_err(|_| error("invalid vertex id"))?;
+                    match rest {
+                        [] => graph.add_edge_unweighted(from, to)?,
+                        [weight] => {
+                            let weight = weight
+                                .parse::<isize>()
+                                .map_err(|_| error("invalid edge weight"))?;
+                            graph.add_edge_weighted(from, to, weight)?;
+                        }
+                        _ => return Err(error("too many fields for an edge")),
+                    }
+                }
+                _ => {
+                    return Err(error(
+                        "expected 'vertex ID VALUE' or 'edge FROM TO [WEIGHT]'",
+                    ))
+                }
+            }
+        }
+        Ok(graph)
+    }
+
+    pub fn from_json(text: &str) -> Result<Self, GraphError> {
+        let error = |reason: &str| GraphError::ParseError(String::from(reason));
+        let Json::Object(fields) = JsonParser::parse(text)? else {
+            return Err(error("expected a JSON object"));
+        };
+        let field = |name: &str| {
+            fields
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value)
+                .ok_or(GraphError::ParseError(format!("missing field '{name}'")))
+        };
+        let kind = match field("kind")? {
+            Json::String(kind) => Self::parse_kind(kind)?,
+            _ => return Err(error("kind must be a string")),
+        };
+        let mut graph = match field("weighted")? {
+            Json::Bool(true) => Graph::new_weighted(kind),
+            Json::Bool(false) => Graph::new_unweighted(kind),
+            _ => return Err(error("weighted must be a boolean")),
+        };
+        let Json::Object(vertices) = field("vertices")? else {
+            return Err(error("vertices must be an object"));
+        };
+        for (id, value) in vertices {
+            let Json::String(value) = value else {
+                return Err(error("vertex values must be strings"));
+            };
+            let id = id.parse::<K>().map_err(|_| error("invalid vertex id"))?;
+            let value = value
+                .parse::<V>()
+                .map_err(|_| error("invalid vertex value"))?;
+            graph.add_vertex(id, value)?;
+        }
+        let Json::Object(edges) = field("edges")? else {
+            return Err(error("edges must be an object"));
+        };
+        let id = |id: &str| id.parse::<K>().map_err(|_| error("invalid vertex id"));
+        for (from, adjacents) in edges {
+            match adjacents {
+                Json::Object(adjacents) => {
+                    for (to, weight) in adjacents {
+                        let Json::Number(weight) = weight else {
+                            return Err(error("edge weights must be integers"));
+                        };
+                        graph.add_edge_weighted(id(from)?, id(to)?, *weight)?;
+                    }
+                }
+                Json::Array(adjacents) => {
+                    for to in adjacents {
+                        let Json::String(to) = to else {
+                            return Err(error("adjacent vertices must be strings"));
+                        };
+                        graph.add_edge_unweighted(id(from)?, id(to)?)?;
+                    }
+                }
+                _ => return Err(error("adjacents must be an object or an array")),
+            }
+        }
+        Ok(graph)
+    }
+
+    fn parse_kind(kind: &str) -> Result<Kind, GraphError> {
+        match kind {
+            "directed" => Ok(Kind::Directed),
+            "undirected" => Ok(Kind::Undirected),
+            _ => Err(GraphError::ParseError(format!(
+                "unknown graph kind '{kind}'"
+            ))),
+        }
+    }
+}
+
+/// Words with whitespace, quotes or control characters are quoted like JSON strings.
+fn quote(s: &str) -> String {
+    let plain = !s.is_empty()
+        && !s.starts_with('#')
+        && !s.contains(|c: char| c.is_whitespace() || c.is_control() || c == '"' || c == '\\');
+    match plain {
+        true => s.to_string(),
+        false => format!("\"{}\"", escape(s)),
+    }
+}
+
+fn escape(s: &str) -> String {
+    let mut escaped = String::new();
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[derive(Debug, PartialEq)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number(isize),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+struct JsonParser<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl JsonParser<'_> {
+    fn parse(text: &str) -> Result<Json, GraphError> {
+        let mut parser = JsonParser {
+            chars: text.chars().peekable(),
+        };
+        let json = parser.value()?;
+        parser.skip_whitespace();
+        match parser.chars.next() {
+            Some(c) => Err(Self::unexpected(Some(c))),
+            None => Ok(json),
+        }
+    }
+
+    fn words(line: &str) -> Result<Vec<String>, GraphError> {
+        let mut parser = JsonParser {
+            chars: line.chars().peekable(),
+        };
+        let mut words: Vec<String> = Vec::new();
+        loop {
+            parser.skip_whitespace();
+            match parser.chars.peek() {
+                Some('"') => words.push(parser.string()?),
+                Some(_) => {
+                    let mut word = String::new();
+                    while let Some(c) = parser.chars.next_if(|c| !c.is_whitespace()) {
+                        word.push(c);
+                    }
+                    words.push(word);
+                }
+                None => return Ok(words),
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, GraphError> {
+        self.skip_whitespace();
+        match self.chars.peek() {
+            Some('{') => self.object(),
+            Some('[') => self.array(),
+            Some('"') => self.string().map(Json::String),
+            Some('t') => self.literal("true", Json::Bool(true)),
+            Some('f') => self.literal("false", Json::Bool(false)),
+            Some('n') => self.literal("null", Json::Null),
+            Some(c) if *c == '-' || c.is_ascii_digit() => self.number(),
+            c => Err(Self::unexpected(c.copied())),
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, GraphError> {
+        self.expect('{')?;
+        let mut fields: Vec<(String, Json)> = Vec::new();
+        self.skip_whitespace();
+        if self.chars.next_if_eq(&'}').is_some() {
+            return Ok(Json::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            fields.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some(',') => continue,
+                Some('}') => return Ok(Json::Object(fields)),
+                c => return Err(Self::unexpected(c)),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, GraphError> {
+        self.expect('[')?;
+        let mut values: Vec<Json> = Vec::new();
+        self.skip_whitespace();
+        if self.chars.next_if_eq(&']').is_some() {
+            return Ok(Json::Array(values));
+        }
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some(',') => continue,
+                Some(']') => return Ok(Json::Array(values)),
+                c => return Err(Self::unexpected(c)),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, GraphError> {
+        self.expect('"')?;
+        let mut s = String::new();
+        loop {
+            match self.chars.next() {
+                Some('"') => return Ok(s),
+                Some('\\') => match self.chars.next() {
+                    Some('"') => s.push('"'),
+                    Some('\\') => s.push('\\'),
+                    Some('/') => s.push('/'),
+                    Some('b') => s.push('\u{8}'),
+                    Some('f') => s.push('\u{c}'),
+                    Some('n') => s.push('\n'),
+                    Some('r') => s.push('\r'),
+                    Some('t') => s.push('\t'),
+                    Some('u') => {
+                        let hex: String = (0..4).filter_map(|_| self.chars.next()).collect();
+                        let c = u32::from_str_radix(&hex, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .ok_or(GraphError::ParseError(format!("invalid escape \\u{hex}")))?;
+                        s.push(c);
+                    }
+                    c => return Err(Self::unexpected(c)),
+                },
+                Some(c) => s.push(c),
+                None => return Err(Self::unexpected(None)),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Json, GraphError> {
+        let mut digits = String::new();
+        while let Some(c) = self.chars.next_if(|c| *c == '-' || c.is_ascii_digit()) {
+            digits.push(c);
+        }
+        digits
+            .parse::<isize>()
+            .map(Json::Number)
+            .map_err(|_| GraphError::ParseError(format!("invalid integer '{digits}'")))
+    }
+
+    fn literal(&mut self, word: &str, json: Json) -> Result<Json, GraphError> {
+        for expected in word.chars() {
+            self.expect(expected)?;
+        }
+        Ok(json)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), GraphError> {
+        match self.chars.next() {
+            Some(c) if c == expected => Ok(()),
+            c => Err(Self::unexpected(c)),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+
+    fn unexpected(c: Option<char>) -> GraphError {
+        match c {
+            Some(c) => GraphError::ParseError(format!("unexpected '{c}' in JSON")),
+            None => GraphError::ParseError(String::from("unexpected end of JSON")),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -450,6 +1277,87 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_remove_vertex_and_edges() -> Result<(), GraphError> {
+        let mut graph = Graph::new_weighted(Kind::Undirected);
+        graph.add_vertex("h", "Homer")?;
+        graph.add_vertex("m", "Marge")?;
+        graph.add_vertex("b", "Bart")?;
+        graph.add_vertex("l", "Lisa")?;
+        graph.add_edge_weighted("h", "m", 13)?;
+        graph.add_edge_weighted("b", "l", 25)?;
+        graph.add_edge_weighted("m", "l", 18)?;
+        graph.add_edge_weighted("h", "b", 78)?;
+
+        assert_eq!(graph.update_weight("l", "m", 20), Ok(()));
+        assert_eq!(
+            graph.get_edges("m"),
+            Ok(HashMap::from([
+                ("l", EdgeType::Weighted(20)),
+                ("h", EdgeType::Weighted(13))
+            ]))
+        );
+        assert_eq!(
+            graph.update_weight("h", "l", 1),
+            Err(GraphError::EdgeInexistant)
+        );
+
+        assert_eq!(graph.remove_edge("b", "h"), Ok(EdgeType::Weighted(78)));
+        assert_eq!(
+            graph.get_edges("h"),
+            Ok(HashMap::from([("m", EdgeType::Weighted(13))]))
+        );
+        assert_eq!(
+            graph.get_edges("b"),
+            Ok(HashMap::from([("l", EdgeType::Weighted(25))]))
+        );
+        assert_eq!(graph.remove_edge("b", "h"), Err(GraphError::EdgeInexistant));
+        assert_eq!(
+            graph.remove_edge("b", "z"),
+            Err(GraphError::VertexInexistant)
+        );
+
+        assert_eq!(
+            graph.remove_vertex("l"),
+            Ok(Vertex {
+                id: "l",
+                value: "Lisa"
+            })
+        );
+        assert_eq!(graph.get_vertex("l"), None);
+        assert_eq!(graph.get_edges("l"), Err(GraphError::VertexInexistant));
+        assert_eq!(graph.get_edges("b"), Ok(HashMap::new()));
+        assert_eq!(
+            graph.get_edges("m"),
+            Ok(HashMap::from([("h", EdgeType::Weighted(13))]))
+        );
+        assert_eq!(graph.remove_vertex("l"), Err(GraphError::VertexInexistant));
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_edge_directed() -> Result<(), GraphError> {
+        let mut graph = Graph::new_unweighted(Kind::Directed);
+        graph.add_vertex("a", "Anderson")?;
+        graph.add_vertex("b", "Beavis")?;
+        graph.add_edge_unweighted("a", "b")?;
+        graph.add_edge_unweighted("b", "a")?;
+
+        assert_eq!(
+            graph.update_weight("a", "b", 3),
+            Err(GraphError::EdgeTypeMismatch)
+        );
+        assert_eq!(graph.remove_edge("a", "b"), Ok(EdgeType::Unweighted));
+        assert_eq!(graph.get_edges("a"), Ok(HashMap::new()));
+        assert_eq!(
+            graph.get_edges("b"),
+            Ok(HashMap::from([("a", EdgeType::Unweighted)]))
+        );
+        graph.remove_vertex("a")?;
+        assert_eq!(graph.get_edges("b"), Ok(HashMap::new()));
+        Ok(())
+    }
+
     #[test]
     fn test_are_vertices_connected() -> Result<(), GraphError> {
         let mut graph = Graph::new_unweighted(Kind::Undirected);
@@ -602,9 +1510,42 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_shortest_paths_negative_weights() -> Result<(), GraphError> {
+        let mut graph = Graph::new_weighted(Kind::Directed);
+        graph.add_vertex("a", "Atlanta")?;
+        graph.add_vertex("b", "Boston")?;
+        graph.add_vertex("c", "Chicago")?;
+        graph.add_vertex("d", "Denver")?;
+
+        graph.add_edge_weighted("a", "b", 4)?;
+        graph.add_edge_weighted("a", "c", 2)?;
+        graph.add_edge_weighted("b", "c", -3)?;
+        graph.add_edge_weighted("c", "d", 2)?;
+
+        assert_eq!(
+            graph.find_shortest_path(&"a", &"d"),
+            Ok((vec!["a", "b", "c", "d"], 3))
+        );
+        assert_eq!(graph.find_shortest_paths("a")?.len(), 4);
+        assert_eq!(graph.find_shortest_paths("b"), Err(GraphError::NoSuchRoute));
+
+        graph.add_edge_weighted("d", "b", 0)?;
+        assert_eq!(
+            graph.find_shortest_paths("a"),
+            Err(GraphError::NegativeCycle)
+        );
+        assert_eq!(
+            graph.find_shortest_path(&"a", &"x"),
+            Err(GraphError::VertexInexistant)
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_backtrack() {
-        let successors = vec![
+        let successors = [
             ("d", "e"),
             ("f", "g"),
             ("b", "c"),
@@ -615,17 +1556,302 @@ mod tests {
             ("a", "b"),
             ("g", "h"),
         ];
+        let parents: HashMap<&str, &str> = successors.into_iter().map(|(a, b)| (b, a)).collect();
 
         let expected = vec!["a", "b", "c", "d", "e", "f", "g", "h", "i"];
-        let actual = Graph::<&str, usize>::backtrack(&"a", &"i", &successors);
+        let actual = Graph::<&str, usize>::backtrack(&"a", &"i", &parents);
         assert_eq!(actual, expected);
 
         let expected = vec!["c", "d", "e", "f", "g"];
-        let actual = Graph::<&str, usize>::backtrack(&"c", &"g", &successors);
+        let actual = Graph::<&str, usize>::backtrack(&"c", &"g", &parents);
         assert_eq!(actual, expected);
 
         let expected = vec!["a"];
-        let actual = Graph::<&str, usize>::backtrack(&"a", &"a", &successors);
+        let actual = Graph::<&str, usize>::backtrack(&"a", &"a", &parents);
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_topological_sort() -> Result<(), GraphError> {
+        let mut graph = Graph::new_unweighted(Kind::Directed);
+        graph.add_vertex("app", "Application")?;
+        graph.add_vertex("core", "Core")?;
+        graph.add_vertex("log", "Logging")?;
+        graph.add_vertex("net", "Networking")?;
+        graph.add_vertex("util", "Utilities")?;
+        graph.add_edge_unweighted("util", "core")?;
+        graph.add_edge_unweighted("log", "core")?;
+        graph.add_edge_unweighted("core", "net")?;
+        graph.add_edge_unweighted("core", "app")?;
+        graph.add_edge_unweighted("net", "app")?;
+
+        assert_eq!(
+            graph.topological_sort(),
+            Ok(vec!["log", "util", "core", "net", "app"])
+        );
+        assert_eq!(graph.find_cycle(), Ok(None));
+
+        graph.add_edge_unweighted("app", "log")?;
+        assert_eq!(graph.topological_sort(), Err(GraphError::CycleDetected));
+        assert_eq!(
+            graph.find_cycle(),
+            Ok(Some(vec!["app", "log", "core", "app"]))
+        );
+
+        let undirected: Graph<&str, &str> = Graph::new_unweighted(Kind::Undirected);
+        assert_eq!(undirected.topological_sort(), Err(GraphError::KindMismatch));
+        Ok(())
+    }
+
+    #[test]
+    fn test_strongly_connected_components() -> Result<(), GraphError> {
+        let mut graph = Graph::new_unweighted(Kind::Directed);
+        for id in ["a", "b", "c", "d", "e", "f", "g"] {
+            graph.add_vertex(id, id.to_uppercase())?;
+        }
+        graph.add_edge_unweighted("a", "b")?;
+        graph.add_edge_unweighted("b", "c")?;
+        graph.add_edge_unweighted("c", "a")?;
+        graph.add_edge_unweighted("c", "d")?;
+        graph.add_edge_unweighted("d", "e")?;
+        graph.add_edge_unweighted("e", "f")?;
+        graph.add_edge_unweighted("f", "d")?;
+        graph.add_edge_unweighted("g", "g")?;
+
+        assert_eq!(
+            graph.strongly_connected_components(),
+            Ok(vec![vec!["d", "e", "f"], vec!["a", "b", "c"], vec!["g"]])
+        );
+        assert_eq!(graph.find_cycle(), Ok(Some(vec!["a", "b", "c", "a"])));
+        Ok(())
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree() -> Result<(), GraphError> {
+        let mut graph = Graph::new_weighted(Kind::Undirected);
+        for id in ["a", "b", "c", "d", "e", "x", "y"] {
+            graph.add_vertex(id, id.to_uppercase())?;
+        }
+        graph.add_edge_weighted("a", "b", 7)?;
+        graph.add_edge_weighted("a", "d", 5)?;
+        graph.add_edge_weighted("b", "c", 8)?;
+        graph.add_edge_weighted("b", "d", 9)?;
+        graph.add_edge_weighted("b", "e", 6)?;
+        graph.add_edge_weighted("c", "e", 4)?;
+        graph.add_edge_weighted("d", "e", 15)?;
+        graph.add_edge_weighted("x", "y", 3)?;
+
+        let expected = HashMap::from([
+            (
+                "a",
+                HashMap::from([("b", EdgeType::Weighted(7)), ("d", EdgeType::Weighted(5))]),
+            ),
+            (
+                "b",
+                HashMap::from([("a", EdgeType::Weighted(7)), ("e", EdgeType::Weighted(6))]),
+            ),
+            ("c", HashMap::from([("e", EdgeType::Weighted(4))])),
+            ("d", HashMap::from([("a", EdgeType::Weighted(5))])),
+            (
+                "e",
+                HashMap::from([("b", EdgeType::Weighted(6)), ("c", EdgeType::Weighted(4))]),
+            ),
+            ("x", HashMap::from([("y", EdgeType::Weighted(3))])),
+            ("y", HashMap::from([("x", EdgeType::Weighted(3))])),
+        ]);
+        for tree in [
+            graph.minimum_spanning_tree_kruskal()?,
+            graph.minimum_spanning_tree_prim()?,
+        ] {
+            assert_eq!(tree.edges, expected);
+            assert_eq!(tree.get_vertex("a"), graph.get_vertex("a"));
+        }
+
+        let directed: Graph<&str, &str> = Graph::new_weighted(Kind::Directed);
+        assert!(matches!(
+            directed.minimum_spanning_tree_kruskal(),
+            Err(GraphError::KindMismatch)
+        ));
+        assert!(matches!(
+            directed.minimum_spanning_tree_prim(),
+            Err(GraphError::KindMismatch)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_connected_components() -> Result<(), GraphError> {
+        let mut graph = Graph::new_unweighted(Kind::Undirected);
+        for id in ["a", "b", "c", "d", "e", "f"] {
+            graph.add_vertex(id, id.to_uppercase())?;
+        }
+        graph.add_edge_unweighted("a", "c")?;
+        graph.add_edge_unweighted("c", "e")?;
+        graph.add_edge_unweighted("d", "b")?;
+
+        assert_eq!(
+            graph.connected_components(),
+            Ok(vec![vec!["a", "c", "e"], vec!["b", "d"], vec!["f"]])
+        );
+        let directed: Graph<&str, &str> = Graph::new_unweighted(Kind::Directed);
+        assert_eq!(
+            directed.connected_components(),
+            Err(GraphError::KindMismatch)
+        );
+        Ok(())
+    }
+
+    fn cities() -> Result<Graph<String, String>, GraphError> {
+        let mut graph = Graph::new_weighted(Kind::Directed);
+        for (id, value) in [("a", "Atlanta"), ("b", "Boston"), ("e", "El Paso")] {
+            graph.add_vertex(String::from(id), String::from(value))?;
+        }
+        graph.add_edge_weighted(String::from("a"), String::from("b"), 100)?;
+        graph.add_edge_weighted(String::from("b"), String::from("e"), -20)?;
+        graph.add_edge_weighted(String::from("e"), String::from("a"), 140)?;
+        Ok(graph)
+    }
+
+    #[test]
+    fn test_to_dot() -> Result<(), GraphError> {
+        let expected = concat!(
+            "digraph {\n",
+            "    \"a\" [label=\"Atlanta\"];\n",
+            "    \"b\" [label=\"Boston\"];\n",
+            "    \"e\" [label=\"El Paso\"];\n",
+            "    \"a\" -> \"b\" [label=\"100\"];\n",
+            "    \"b\" -> \"e\" [label=\"-20\"];\n",
+            "    \"e\" -> \"a\" [label=\"140\"];\n",
+            "}\n"
+        );
+        assert_eq!(cities()?.to_dot(), expected);
+
+        let mut graph = Graph::new_unweighted(Kind::Undirected);
+        graph.add_vertex("a", "Anderson \"Andy\"")?;
+        graph.add_vertex("b", "Beavis")?;
+        graph.add_edge_unweighted("b", "a")?;
+        let expected = concat!(
+            "graph {\n",
+            "    \"a\" [label=\"Anderson \\\"Andy\\\"\"];\n",
+            "    \"b\" [label=\"Beavis\"];\n",
+            "    \"a\" -- \"b\";\n",
+            "}\n"
+        );
+        assert_eq!(graph.to_dot(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_edge_list_round_trip() -> Result<(), GraphError> {
+        let graph = cities()?;
+        let list = graph.to_edge_list();
+        assert_eq!(
+            list,
+            concat!(
+                "directed weighted\n",
+                "vertex a Atlanta\n",
+                "vertex b Boston\n",
+                "vertex e \"El Paso\"\n",
+                "edge a b 100\n",
+                "edge b e -20\n",
+                "edge e a 140\n",
+            )
+        );
+        let parsed: Graph<String, String> = Graph::from_edge_list(&list)?;
+        assert_eq!(parsed.edges, graph.edges);
+        assert_eq!(parsed.vertices, graph.vertices);
+
+        let mut graph: Graph<String, String> = Graph::new_weighted(Kind::Undirected);
+        graph.add_vertex(String::from("New York"), String::from("The \"Big\"\nApple"))?;
+        graph.add_vertex(String::from("#1"), String::new())?;
+        graph.add_edge_weighted(String::from("New York"), String::from("#1"), 5)?;
+        let list = graph.to_edge_list();
+        assert_eq!(
+            list,
+            concat!(
+                "undirected weighted\n",
+                "vertex \"#1\" \"\"\n",
+                "vertex \"New York\" \"The \\\"Big\\\"\\nApple\"\n",
+                "edge \"#1\" \"New York\" 5\n",
+            )
+        );
+        let parsed: Graph<String, String> = Graph::from_edge_list(&list)?;
+        assert_eq!(parsed.edges, graph.edges);
+        assert_eq!(parsed.vertices, graph.vertices);
+
+        let undirected =
+            "# family\nundirected unweighted\nvertex 1 Homer\nvertex 2 Marge\nedge 2 1\n";
+        let parsed: Graph<u32, String> = Graph::from_edge_list(undirected)?;
+        assert_eq!(
+            parsed.get_edges(1),
+            Ok(HashMap::from([(2, EdgeType::Unweighted)]))
+        );
+        assert_eq!(
+            parsed.to_edge_list(),
+            "undirected unweighted\nvertex 1 Homer\nvertex 2 Marge\nedge 1 2\n"
+        );
+
+        assert!(matches!(
+            Graph::<u32, String>::from_edge_list("directed weighted\nedge 1 2 x\n"),
+            Err(GraphError::ParseError(_))
+        ));
+        assert!(matches!(
+            Graph::<u32, String>::from_edge_list(
+                "directed unweighted\nvertex 1 a\nvertex 2 b\nedge 1 2 3\n"
+            ),
+            Err(GraphError::EdgeTypeMismatch)
+        ));
+        assert_eq!(
+            Graph::<u32, String>::from_edge_list("# cities\n\ndirected\n").err(),
+            Some(GraphError::ParseError(String::from(
+                "line 3: expected '<directed|undirected> <weighted|unweighted>', got 'directed'"
+            )))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_round_trip() -> Result<(), GraphError> {
+        let graph = cities()?;
+        let json = graph.to_json();
+        assert_eq!(
+            json,
+            concat!(
+                "{\"kind\":\"directed\",\"weighted\":true,",
+                "\"vertices\":{\"a\":\"Atlanta\",\"b\":\"Boston\",\"e\":\"El Paso\"},",
+                "\"edges\":{\"a\":{\"b\":100},\"b\":{\"e\":-20},\"e\":{\"a\":140}}}\n"
+            )
+        );
+        let parsed: Graph<String, String> = Graph::from_json(&json)?;
+        assert_eq!(parsed.edges, graph.edges);
+        assert_eq!(parsed.vertices, graph.vertices);
+
+        let mut graph: Graph<String, String> = Graph::new_unweighted(Kind::Undirected);
+        graph.add_vertex(String::from("q\"t"), String::from("line\nbreak"))?;
+        graph.add_vertex(String::from("z"), String::new())?;
+        graph.add_edge_unweighted(String::from("z"), String::from("q\"t"))?;
+        let parsed: Graph<String, String> = Graph::from_json(&graph.to_json())?;
+        assert_eq!(parsed.edges, graph.edges);
+        assert_eq!(parsed.vertices, graph.vertices);
+        assert_eq!(parsed.to_json(), graph.to_json());
+
+        let pretty = r#"{
+            "kind": "directed", "weighted": false,
+            "vertices": {"x": "X"}, "edges": {"x": ["x"]}
+        }"#;
+        let parsed: Graph<String, String> = Graph::from_json(pretty)?;
+        assert_eq!(
+            parsed.get_vertex(String::from("x")).map(|v| v.value),
+            Some(String::from("X"))
+        );
+        assert!(matches!(
+            Graph::<String, String>::from_json("{\"kind\":\"directed\"}"),
+            Err(GraphError::ParseError(_))
+        ));
+        assert!(matches!(
+            Graph::<String, String>::from_json("[1, 2"),
+            Err(GraphError::ParseError(_))
+        ));
+        Ok(())
+    }
 }
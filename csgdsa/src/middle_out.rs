@@ -34,7 +34,7 @@ fn split_median<T: Ord + Clone>(values: &[T]) -> (Option<T>, Vec<Vec<T>>) {
     } else {
         let mut values = values.to_owned();
         values.sort();
-        let m = if n % 2 == 0 { n / 2 - 1 } else { n / 2 };
+        let m = (n - 1) / 2;
         let median = &values[m];
         let left = values[0..m].to_owned();
         let right = values[m + 1..n].to_owned();
@@ -302,7 +302,7 @@ mod tests {
             words.insert(&line);
         }
         let completions = words.autocomplete("aban");
-        let expected = vec!["don", "doned", "doning", "donment", "donment's", "dons"];
+        let expected = ["don", "doned", "doning", "donment", "donment's", "dons"];
         let expected: Vec<(String, String)> = expected
             .iter()
             .map(|s| (String::from("aban"), String::from(*s)))
@@ -321,15 +321,14 @@ mod tests {
         }
 
         let actual = words.autocorrect("catnar");
-        let expected: Vec<String> =
-            vec!["catnap", "catnap's", "catnapped", "catnapping", "catnaps"]
-                .iter()
-                .map(|s| String::from(*s))
-                .collect();
+        let expected: Vec<String> = ["catnap", "catnap's", "catnapped", "catnapping", "catnaps"]
+            .iter()
+            .map(|s| String::from(*s))
+            .collect();
         assert_eq!(actual, Some(expected));
 
         let actual = words.autocorrect("membrax");
-        let expected: Vec<String> = vec!["membrane", "membrane's", "membranes", "membranous"]
+        let expected: Vec<String> = ["membrane", "membrane's", "membranes", "membranous"]
             .iter()
             .map(|s| String::from(*s))
             .collect();
@@ -349,8 +348,6 @@ mod tests {
             Ok(text) => text,
             Err(err) => panic!("decoding utf8: {err}"),
         };
-        text.split('\n')
-            .map(|s| String::from(s))
-            .collect::<Vec<String>>()
+        text.split('\n').map(String::from).collect::<Vec<String>>()
     }
 }
@@ -0,0 +1,124 @@
+use crate::parsing::read_lines;
+use crate::table_row::TableRow;
+use crate::{Aliases, Failure};
+use std::fmt::{Display, Error, Formatter};
+use std::path::Path;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Adjustment {
+    pub team: String,
+    pub points: i32,
+    pub reason: String,
+    pub from_day: usize,
+}
+
+impl Display for Adjustment {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(
+            f,
+            "{}: {:+} points from matchday {} ({})",
+            self.team, self.points, self.from_day, self.reason
+        )
+    }
+}
+
+pub fn load_adjustments(path: &Path) -> Result<Vec<Adjustment>, Failure> {
+    let lines =
+        read_lines(path).map_err(|e| Failure::Io(format!("read {}: {e}", path.display())))?;
+    let mut adjustments: Vec<Adjustment> = Vec::new();
+    for (number, line) in lines {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let error =
+            |reason: String| Failure::Parsing(format!("{}:{number}: {reason}", path.display()));
+        let fields: Vec<&str> = line.split(';').map(|f| f.trim()).collect();
+        let [team, points, reason, rest @ ..] = fields.as_slice() else {
+            return Err(error(format!(
+                "expected 'Team; points; reason[; from matchday]', got '{line}'"
+            )));
+        };
+        let points = points
+            .parse::<i32>()
+            .map_err(|e| error(format!("parse points '{points}': {e}")))?;
+        let from_day = match rest {
+            [] => 1,
+            [day] => day
+                .parse::<usize>()
+                .map_err(|e| error(format!("parse matchday '{day}': {e}")))?,
+            _ => return Err(error(format!("too many fields in '{line}'"))),
+        };
+        adjustments.push(Adjustment {
+            team: team.to_string(),
+            points,
+            reason: reason.to_string(),
+            from_day,
+        });
+    }
+    Ok(adjustments)
+}
+
+pub fn apply_adjustments(
+    rows: &mut [TableRow],
+    adjustments: &[Adjustment],
+    names: &Aliases,
+    day: usize,
+) -> Result<Vec<Adjustment>, Failure> {
+    let mut applied: Vec<Adjustment> = Vec::new();
+    for adjustment in adjustments.iter().filter(|a| a.from_day <= day) {
+        let team = names.canonical(&adjustment.team);
+        if let Some(row) = rows.iter_mut().find(|r| r.team == team) {
+            row.points = row
+                .points
+                .checked_add(adjustment.points)
+                .ok_or_else(|| Failure::Overflow(format!("points of team '{team}' overflow")))?;
+            applied.push(Adjustment {
+                team,
+                ..adjustment.clone()
+            });
+        }
+    }
+    Ok(applied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+    use std::fs;
+
+    #[test]
+    fn load_and_apply() {
+        let path = temp_dir("soccer-table-test-adjustments").join("adjustments.txt");
+        fs::write(
+            &path,
+            "# deductions\nFC Sion; -3; unpaid wages; 5\nGC; +2; walkover awarded\n",
+        )
+        .unwrap();
+        let adjustments = load_adjustments(&path).unwrap();
+        assert_eq!(
+            adjustments[0],
+            Adjustment {
+                team: String::from("FC Sion"),
+                points: -3,
+                reason: String::from("unpaid wages"),
+                from_day: 5,
+            }
+        );
+        assert_eq!(adjustments[1].from_day, 1);
+
+        let mut rows = vec![TableRow::new("FC Sion"), TableRow::new("GC")];
+        rows[0].points = 7;
+        let applied = apply_adjustments(&mut rows, &adjustments, &Aliases::default(), 4).unwrap();
+        assert_eq!(applied.len(), 1);
+        assert_eq!((rows[0].points, rows[1].points), (7, 2));
+
+        let mut rows = vec![TableRow::new("FC Sion"), TableRow::new("GC")];
+        rows[0].points = 2;
+        apply_adjustments(&mut rows, &adjustments, &Aliases::default(), 5).unwrap();
+        assert_eq!((rows[0].points, rows[1].points), (-1, 2));
+
+        fs::write(&path, "FC Sion; three; unpaid wages\n").unwrap();
+        assert!(matches!(load_adjustments(&path), Err(Failure::Parsing(_))));
+    }
+}
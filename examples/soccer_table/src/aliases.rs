@@ -0,0 +1,83 @@
+use crate::parsing::read_lines;
+use crate::Failure;
+use std::collections::HashMap;
+use std::path::Path;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Aliases {
+    names: HashMap<String, String>,
+}
+
+impl Aliases {
+    pub fn load(path: &Path) -> Result<Aliases, Failure> {
+        let lines =
+            read_lines(path).map_err(|e| Failure::Io(format!("read {}: {e}", path.display())))?;
+        let mut aliases = Aliases::default();
+        for (number, line) in lines {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((canonical, spellings)) = line.split_once('=') else {
+                return Err(Failure::Parsing(format!(
+                    "{}:{number}: expected 'Canonical Name = Alias, ...', got '{line}'",
+                    path.display()
+                )));
+            };
+            aliases.insert(canonical, spellings.split(','));
+        }
+        Ok(aliases)
+    }
+
+    pub fn insert<'a>(&mut self, canonical: &str, spellings: impl Iterator<Item = &'a str>) {
+        let canonical = collapse(canonical);
+        for spelling in spellings.filter(|s| !s.trim().is_empty()) {
+            self.names.insert(normalize(spelling), canonical.clone());
+        }
+        self.names.insert(normalize(&canonical), canonical);
+    }
+
+    pub fn canonical(&self, name: &str) -> String {
+        match self.names.get(&normalize(name)) {
+            Some(canonical) => canonical.clone(),
+            None => collapse(name),
+        }
+    }
+
+    pub fn learn(&mut self, name: &str) -> String {
+        self.names
+            .entry(normalize(name))
+            .or_insert_with(|| collapse(name))
+            .clone()
+    }
+}
+
+fn collapse(name: &str) -> String {
+    name.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+fn normalize(name: &str) -> String {
+    collapse(name).to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_names() {
+        let mut aliases = Aliases::default();
+        aliases.insert("FC Basel", ["FC Basel 1893", "Basel"].into_iter());
+        assert_eq!(aliases.canonical("FC Basel 1893"), "FC Basel");
+        assert_eq!(aliases.canonical("  fc   BASEL "), "FC Basel");
+        assert_eq!(aliases.canonical("basel"), "FC Basel");
+        assert_eq!(aliases.canonical("FC  Zürich "), "FC Zürich");
+    }
+
+    #[test]
+    fn learn_first_spelling() {
+        let mut aliases = Aliases::default();
+        assert_eq!(aliases.learn("Young Boys"), "Young Boys");
+        assert_eq!(aliases.learn("young  boys"), "Young Boys");
+        assert_eq!(aliases.canonical("YOUNG BOYS"), "Young Boys");
+    }
+}
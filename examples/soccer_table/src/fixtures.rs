@@ -0,0 +1,265 @@
+use crate::parsing::{read_lines, Diagnostic, Fixture, MatchResult, ParseError, Record};
+use crate::table_row::TableRow;
+use crate::{Failure, Table};
+use std::collections::HashMap;
+use std::fmt::{Display, Error, Formatter};
+use std::path::Path;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Projection {
+    pub rank: usize,
+    pub team: String,
+    pub points: i32,
+    pub remaining: usize,
+    pub max_points: i32,
+    pub best: usize,
+    pub worst: usize,
+}
+
+pub struct Projections {
+    pub rows: Vec<Projection>,
+    pub relegation: usize,
+}
+
+impl Projection {
+    pub fn is_champion(&self) -> bool {
+        self.worst == 1
+    }
+
+    pub fn is_relegated(&self, teams: usize, relegation: usize) -> bool {
+        relegation > 0 && self.best > teams.saturating_sub(relegation)
+    }
+}
+
+impl Display for Projections {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let title = format!(
+            "{:>3} {:30} {:>4} {:>4} {:>4} {:>4} {:>4} {}",
+            "#", "Team", "P", "Left", "Max", "Best", "Wrst", "Status"
+        );
+        f.write_fmt(format_args!("{}\n", title))?;
+        f.write_fmt(format_args!("{}\n", "-".repeat(title.chars().count())))?;
+        let teams = self.rows.len();
+        for p in &self.rows {
+            let status = if p.is_champion() {
+                "champion"
+            } else if p.is_relegated(teams, self.relegation) {
+                "relegated"
+            } else {
+                ""
+            };
+            let line = format!(
+                "{:>3} {:30} {:>4} {:>4} {:>4} {:>4} {:>4} {}",
+                p.rank, p.team, p.points, p.remaining, p.max_points, p.best, p.worst, status
+            );
+            f.write_fmt(format_args!("{}\n", line.trim_end()))?;
+        }
+        Ok(())
+    }
+}
+
+pub fn load_fixtures(path: &Path) -> Result<Vec<Fixture>, Failure> {
+    let lines =
+        read_lines(path).map_err(|e| Failure::Io(format!("read {}: {e}", path.display())))?;
+    let (numbers, lines): (Vec<usize>, Vec<String>) = lines.into_iter().unzip();
+    let records = MatchResult::parse_all(lines).map_err(|e| Failure::Parsing(format!("{e}")))?;
+    let mut fixtures: Vec<Fixture> = Vec::new();
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    for (line, record) in numbers.into_iter().zip(records) {
+        let err = match record {
+            Ok(Record::Postponed(fixture)) => {
+                fixtures.push(fixture);
+                continue;
+            }
+            Ok(Record::Ignored) => continue,
+            Ok(Record::Played(r)) => ParseError::Inconsistent {
+                val: format!(
+                    "{} {}:{} {}",
+                    r.home_team, r.home_goals, r.away_goals, r.away_team
+                ),
+                reason: "expected an open fixture like 'Home -:- Away'",
+            },
+            Err(err) => err,
+        };
+        diagnostics.push(Diagnostic {
+            path: path.to_path_buf(),
+            line,
+            err,
+        });
+    }
+    match diagnostics.is_empty() {
+        true => Ok(fixtures),
+        false => Err(Failure::Diagnostics(diagnostics)),
+    }
+}
+
+impl Table {
+    pub fn open_fixtures(&self, schedule: &[Fixture]) -> Vec<Fixture> {
+        let mut played: Vec<(&str, &str)> = self
+            .results
+            .iter()
+            .map(|r| (r.home_team.as_str(), r.away_team.as_str()))
+            .collect();
+        let mut open: Vec<Fixture> = Vec::new();
+        for fixture in schedule {
+            let fixture = Fixture {
+                home_team: self.canonical(&fixture.home_team),
+                away_team: self.canonical(&fixture.away_team),
+            };
+            let pairing = (fixture.home_team.as_str(), fixture.away_team.as_str());
+            match played.iter().position(|p| *p == pairing) {
+                Some(i) => {
+                    played.swap_remove(i);
+                }
+                None => open.push(fixture),
+            }
+        }
+        for fixture in &self.postponed {
+            if !open.contains(fixture) {
+                open.push(fixture.clone());
+            }
+        }
+        open
+    }
+
+    pub fn remaining_for(&self, team: &str, schedule: &[Fixture]) -> Vec<Fixture> {
+        let team = self.canonical(team);
+        self.open_fixtures(schedule)
+            .into_iter()
+            .filter(|f| f.home_team == team || f.away_team == team)
+            .collect()
+    }
+
+    pub fn projections(
+        &self,
+        schedule: &[Fixture],
+        relegation: usize,
+    ) -> Result<Projections, Failure> {
+        let open = self.open_fixtures(schedule);
+        let ranked = self.ranked()?;
+        let max_per_match = self.rules.points.max_per_match();
+        let min_per_match = self.rules.points.min_per_match();
+        let games = |team: &str| {
+            open.iter()
+                .filter(|f| f.home_team == team || f.away_team == team)
+                .count()
+        };
+        let projected = |row: &TableRow, per_match: i32| {
+            i32::try_from(games(&row.team))
+                .ok()
+                .and_then(|games| games.checked_mul(per_match))
+                .and_then(|points| points.checked_add(row.points))
+                .ok_or_else(|| {
+                    Failure::Overflow(format!("projected points of team '{}' overflow", row.team))
+                })
+        };
+        // (worst, best) final points per team
+        let bounds: HashMap<&str, (i32, i32)> = ranked
+            .iter()
+            .map(|row| {
+                let bounds = (
+                    projected(row, min_per_match)?,
+                    projected(row, max_per_match)?,
+                );
+                Ok((row.team.as_str(), bounds))
+            })
+            .collect::<Result<_, Failure>>()?;
+        let rows = ranked
+            .iter()
+            .map(|row| {
+                let (worst_points, best_points) = bounds[row.team.as_str()];
+                let others = ranked.iter().filter(|o| o.team != row.team);
+                let surely_ahead = others
+                    .clone()
+                    .filter(|o| bounds[o.team.as_str()].0 > best_points)
+                    .count();
+                let possibly_ahead = others
+                    .filter(|o| bounds[o.team.as_str()].1 >= worst_points)
+                    .count();
+                Projection {
+                    rank: row.rank,
+                    team: row.team.clone(),
+                    points: row.points,
+                    remaining: games(&row.team),
+                    max_points: best_points,
+                    best: surely_ahead + 1,
+                    worst: possibly_ahead + 1,
+                }
+            })
+            .collect();
+        Ok(Projections { rows, relegation })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::{fixture, result};
+    use crate::{build_table, Failure, PointsScheme, RankingRules};
+
+    #[test]
+    fn open_fixtures_exclude_played_games() {
+        let results = vec![result("A", 1, 0, "B"), result("C", 0, 0, "D")];
+        let table = build_table(results, &RankingRules::default()).unwrap();
+        let schedule = vec![
+            fixture("A", "B"),
+            fixture("C", "D"),
+            fixture("B", "A"),
+            fixture("D", "C"),
+        ];
+        assert_eq!(
+            table.open_fixtures(&schedule),
+            vec![fixture("B", "A"), fixture("D", "C")]
+        );
+        assert_eq!(table.remaining_for("A", &schedule), vec![fixture("B", "A")]);
+    }
+
+    #[test]
+    fn project_best_and_worst_positions() {
+        let results = vec![
+            result("A", 1, 0, "B"),
+            result("C", 0, 1, "D"),
+            result("A", 2, 0, "C"),
+            result("B", 1, 1, "D"),
+            result("A", 3, 0, "D"),
+            result("B", 1, 0, "C"),
+        ];
+        let table = build_table(results, &RankingRules::default()).unwrap();
+        let schedule = vec![fixture("D", "A"), fixture("C", "B")];
+        let projections = table.projections(&schedule, 1).unwrap();
+        let summary: Vec<(&str, usize, i32, usize, usize)> = projections
+            .rows
+            .iter()
+            .map(|p| (p.team.as_str(), p.remaining, p.max_points, p.best, p.worst))
+            .collect();
+        // A: 9 points, B: 4, D: 4, C: 0
+        assert_eq!(
+            summary,
+            vec![
+                ("A", 1, 12, 1, 1),
+                ("B", 1, 7, 2, 3),
+                ("D", 1, 7, 2, 3),
+                ("C", 1, 3, 4, 4),
+            ]
+        );
+        assert!(projections.rows[0].is_champion());
+        assert!(projections.rows[3].is_relegated(4, 1));
+        assert!(!projections.rows[2].is_relegated(4, 1));
+    }
+
+    #[test]
+    fn projections_report_overflowing_points() {
+        let rules = RankingRules {
+            points: PointsScheme {
+                win: i32::MAX / 2 + 1,
+                ..PointsScheme::default()
+            },
+            ..RankingRules::default()
+        };
+        let table = build_table(vec![result("A", 1, 0, "B")], &rules).unwrap();
+        let schedule = vec![fixture("B", "A")];
+        assert!(matches!(
+            table.projections(&schedule, 0),
+            Err(Failure::Overflow(_))
+        ));
+    }
+}
@@ -0,0 +1,240 @@
+use crate::table_row::TableRow;
+use crate::{Failure, Table};
+use std::str::FromStr;
+
+pub const COLUMNS: [&str; 9] = [
+    "rank",
+    "team",
+    "points",
+    "wins",
+    "ties",
+    "defeats",
+    "goals_scored",
+    "goals_conceded",
+    "goals_diff",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TableFormat {
+    Text,
+    Json,
+    Csv,
+    Markdown,
+    Html,
+}
+
+impl FromStr for TableFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(TableFormat::Text),
+            "json" => Ok(TableFormat::Json),
+            "csv" => Ok(TableFormat::Csv),
+            "markdown" | "md" => Ok(TableFormat::Markdown),
+            "html" => Ok(TableFormat::Html),
+            _ => Err(format!("unknown format '{s}'")),
+        }
+    }
+}
+
+impl TableFormat {
+    pub fn render(&self, table: &Table) -> Result<String, Failure> {
+        let rows = table.ranked()?;
+        Ok(match self {
+            TableFormat::Text => format!("{table}"),
+            TableFormat::Json => render_json(table, &rows),
+            TableFormat::Csv => render_csv(table, &rows),
+            TableFormat::Markdown => render_markdown(table, &rows),
+            TableFormat::Html => render_html(table, &rows),
+        })
+    }
+}
+
+fn columns(table: &Table) -> Vec<&'static str> {
+    let mut columns = COLUMNS.to_vec();
+    if table.form_length > 0 {
+        columns.push("form");
+    }
+    columns
+}
+
+fn fields(table: &Table, row: &TableRow) -> Vec<String> {
+    let mut fields = vec![
+        row.rank.to_string(),
+        row.team.clone(),
+        row.points.to_string(),
+        row.wins.to_string(),
+        row.ties.to_string(),
+        row.defeats.to_string(),
+        row.goals_scored.to_string(),
+        row.goals_conceded.to_string(),
+        row.goals_diff.to_string(),
+    ];
+    if table.form_length > 0 {
+        fields.push(table.form(&row.team));
+    }
+    fields
+}
+
+fn render_json(table: &Table, rows: &[TableRow]) -> String {
+    let objects: Vec<String> = rows.iter().map(|r| row_json(table, r)).collect();
+    format!("[{}]\n", objects.join(","))
+}
+
+pub fn row_json(table: &Table, row: &TableRow) -> String {
+    let members: Vec<String> = columns(table)
+        .iter()
+        .zip(fields(table, row))
+        .map(|(c, v)| match *c {
+            "team" | "form" => format!("\"{c}\":\"{}\"", escape_json(&v)),
+            _ => format!("\"{c}\":{v}"),
+        })
+        .collect();
+    format!("{{{}}}", members.join(","))
+}
+
+fn render_csv(table: &Table, rows: &[TableRow]) -> String {
+    let mut out = format!("{}\n", columns(table).join(","));
+    for r in rows {
+        let values: Vec<String> = fields(table, r).iter().map(|v| escape_csv(v)).collect();
+        out.push_str(&format!("{}\n", values.join(",")));
+    }
+    out
+}
+
+fn render_markdown(table: &Table, rows: &[TableRow]) -> String {
+    let columns = columns(table);
+    let alignments: Vec<&str> = columns
+        .iter()
+        .map(|c| match *c {
+            "team" | "form" => ":---",
+            _ => "---:",
+        })
+        .collect();
+    let mut out = format!("| {} |\n", columns.join(" | "));
+    out.push_str(&format!("| {} |\n", alignments.join(" | ")));
+    for r in rows {
+        let values: Vec<String> = fields(table, r)
+            .iter()
+            .map(|v| v.replace('|', "\\|"))
+            .collect();
+        out.push_str(&format!("| {} |\n", values.join(" | ")));
+    }
+    out
+}
+
+fn render_html(table: &Table, rows: &[TableRow]) -> String {
+    let mut out = String::from("<table>\n<thead>\n<tr>");
+    for c in columns(table) {
+        out.push_str(&format!("<th>{c}</th>"));
+    }
+    out.push_str("</tr>\n</thead>\n<tbody>\n");
+    for r in rows {
+        out.push_str("<tr>");
+        for v in fields(table, r) {
+            out.push_str(&format!("<td>{}</td>", escape_html(&v)));
+        }
+        out.push_str("</tr>\n");
+    }
+    out.push_str("</tbody>\n</table>\n");
+    out
+}
+
+pub fn escape_json(s: &str) -> String {
+    let mut escaped = String::new();
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+pub(crate) fn escape_csv(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RankingRules;
+
+    fn table() -> Table {
+        let mut a = TableRow::new("FC \"Rust\", Bern");
+        a.points = 3;
+        a.wins = 1;
+        a.goals_scored = 2;
+        a.goals_diff = 2;
+        let mut b = TableRow::new("COBOL <1958>");
+        b.defeats = 1;
+        b.goals_conceded = 2;
+        b.goals_diff = -2;
+        Table::new(vec![b, a], Vec::new(), RankingRules::default())
+    }
+
+    #[test]
+    fn parse_format() {
+        assert_eq!("JSON".parse(), Ok(TableFormat::Json));
+        assert_eq!("md".parse(), Ok(TableFormat::Markdown));
+        assert!("xml".parse::<TableFormat>().is_err());
+    }
+
+    #[test]
+    fn render_as_json() {
+        let expected = concat!(
+            "[{\"rank\":1,\"team\":\"FC \\\"Rust\\\", Bern\",\"points\":3,\"wins\":1,",
+            "\"ties\":0,\"defeats\":0,\"goals_scored\":2,\"goals_conceded\":0,\"goals_diff\":2},",
+            "{\"rank\":2,\"team\":\"COBOL <1958>\",\"points\":0,\"wins\":0,",
+            "\"ties\":0,\"defeats\":1,\"goals_scored\":0,\"goals_conceded\":2,\"goals_diff\":-2}]\n"
+        );
+        assert_eq!(TableFormat::Json.render(&table()).unwrap(), expected);
+    }
+
+    #[test]
+    fn render_as_csv() {
+        let expected = concat!(
+            "rank,team,points,wins,ties,defeats,goals_scored,goals_conceded,goals_diff\n",
+            "1,\"FC \"\"Rust\"\", Bern\",3,1,0,0,2,0,2\n",
+            "2,COBOL <1958>,0,0,0,1,0,2,-2\n"
+        );
+        assert_eq!(TableFormat::Csv.render(&table()).unwrap(), expected);
+    }
+
+    #[test]
+    fn render_form_column() {
+        let table = table().with_form(5);
+        let csv = TableFormat::Csv.render(&table).unwrap();
+        assert!(csv.starts_with(
+            "rank,team,points,wins,ties,defeats,goals_scored,goals_conceded,goals_diff,form\n"
+        ));
+        let json = TableFormat::Json.render(&table).unwrap();
+        assert!(json.contains("\"goals_diff\":2,\"form\":\"\"}"));
+    }
+
+    #[test]
+    fn render_as_markdown_and_html() {
+        let markdown = TableFormat::Markdown.render(&table()).unwrap();
+        assert!(markdown.starts_with("| rank | team | points |"));
+        assert!(markdown.contains("| 2 | COBOL <1958> | 0 |"));
+        let html = TableFormat::Html.render(&table()).unwrap();
+        assert!(html.contains("<th>goals_diff</th>"));
+        assert!(html.contains("<td>COBOL &lt;1958&gt;</td>"));
+    }
+}
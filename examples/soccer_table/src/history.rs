@@ -0,0 +1,164 @@
+use crate::adjustments::apply_adjustments;
+use crate::format::escape_csv;
+use crate::parsing::{Diagnostic, MatchResult};
+use crate::table_row::TableRow;
+use crate::{aggregate, Failure, Matchdays, Options};
+use std::collections::HashMap;
+use std::fmt::{Display, Error, Formatter};
+use std::path::Path;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RankHistory {
+    pub days: Vec<usize>,
+    pub ranks: Vec<(String, Vec<Option<usize>>)>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl RankHistory {
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("team");
+        for day in &self.days {
+            out.push_str(&format!(",{day}"));
+        }
+        out.push('\n');
+        for (team, ranks) in &self.ranks {
+            out.push_str(&escape_csv(team));
+            for rank in ranks {
+                out.push(',');
+                if let Some(rank) = rank {
+                    out.push_str(&rank.to_string());
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl Display for RankHistory {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let mut title = format!("{:30}", "Team");
+        for day in &self.days {
+            title.push_str(&format!(" {day:>3}"));
+        }
+        f.write_fmt(format_args!("{}\n", title))?;
+        f.write_fmt(format_args!("{}\n", "-".repeat(title.chars().count())))?;
+        for (team, ranks) in &self.ranks {
+            f.write_fmt(format_args!("{team:30}"))?;
+            for rank in ranks {
+                match rank {
+                    Some(rank) => f.write_fmt(format_args!(" {rank:>3}"))?,
+                    None => f.write_fmt(format_args!(" {:>3}", "-"))?,
+                }
+            }
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
+
+pub fn compute_history(dir: &Path, options: &Options) -> Result<RankHistory, Failure> {
+    let mut matchdays = Matchdays::new(dir, options)?;
+    let rules = &options.rules;
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    let mut days: Vec<usize> = Vec::new();
+    let mut snapshots: Vec<HashMap<String, usize>> = Vec::new();
+    let mut results: Vec<MatchResult> = Vec::new();
+    let mut rows: Vec<TableRow> = Vec::new();
+    while let Some(matchday) = matchdays.next() {
+        let (day, mut parsed) = matchday?;
+        let day_results = parsed.results;
+        diagnostics.append(&mut parsed.diagnostics);
+        let day_rows = day_results.iter().flat_map(|r| {
+            let (home, away) = TableRow::from_with_points(r.clone(), &rules.points);
+            [home, away]
+        });
+        rows = aggregate(rows.into_iter().chain(day_rows).collect())?;
+        results.extend(day_results);
+        let mut adjusted = rows.clone();
+        apply_adjustments(&mut adjusted, &options.adjustments, matchdays.names(), day)?;
+        let ranked = rules.rank(&adjusted, &results)?;
+        snapshots.push(
+            ranked
+                .iter()
+                .enumerate()
+                .map(|(i, r)| (r.team.clone(), i + 1))
+                .collect(),
+        );
+        days.push(day);
+    }
+
+    let mut teams: Vec<(String, usize)> = match snapshots.last() {
+        Some(last) => last.iter().map(|(t, r)| (t.clone(), *r)).collect(),
+        None => Vec::new(),
+    };
+    teams.sort_by_key(|(_, rank)| *rank);
+    let ranks = teams
+        .into_iter()
+        .map(|(team, _)| {
+            let history = snapshots.iter().map(|s| s.get(&team).copied()).collect();
+            (team, history)
+        })
+        .collect();
+    Ok(RankHistory {
+        days,
+        ranks,
+        diagnostics,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+    use crate::Window;
+    use std::fs;
+
+    #[test]
+    fn compute_history_per_matchday() {
+        let test_dir = temp_dir("soccer-table-test-history");
+        let path = test_dir.as_path();
+        fs::write(path.join("01.txt"), "A 1:0 B\n").unwrap();
+        fs::write(path.join("02.txt"), "B 2:0 C\n").unwrap();
+        fs::write(path.join("03.txt"), "C 0:3 B\nA 0:0 C\n").unwrap();
+
+        let expected = RankHistory {
+            days: vec![1, 2, 3],
+            ranks: vec![
+                (String::from("B"), vec![Some(2), Some(2), Some(1)]),
+                (String::from("A"), vec![Some(1), Some(1), Some(2)]),
+                (String::from("C"), vec![None, Some(3), Some(3)]),
+            ],
+            diagnostics: Vec::new(),
+        };
+        let actual = compute_history(path, &Options::default()).unwrap();
+        assert_eq!(actual, expected);
+
+        let options = Options {
+            window: Window {
+                from_day: Some(2),
+                ..Window::default()
+            },
+            ..Options::default()
+        };
+        let actual = compute_history(path, &options).unwrap();
+        assert_eq!(actual.days, vec![2, 3]);
+        assert_eq!(actual.ranks[0], (String::from("B"), vec![Some(1), Some(1)]));
+    }
+
+    #[test]
+    fn render_history() {
+        let history = RankHistory {
+            days: vec![1, 2],
+            ranks: vec![
+                (String::from("A"), vec![Some(2), Some(1)]),
+                (String::from("B, Jr."), vec![None, Some(2)]),
+            ],
+            diagnostics: Vec::new(),
+        };
+        assert_eq!(history.to_csv(), "team,1,2\nA,2,1\n\"B, Jr.\",,2\n");
+        let text = format!("{history}");
+        assert!(text.contains(&format!("{:30}   2   1\n", "A")));
+        assert!(text.contains(&format!("{:30}   -   2\n", "B, Jr.")));
+    }
+}
@@ -0,0 +1,272 @@
+use crate::format::escape_json;
+use crate::parsing::{Decision, Diagnostic, MatchResult};
+use crate::{Failure, Matchdays, Options};
+use std::cmp::Ordering;
+use std::fmt::{Display, Error, Formatter};
+use std::path::Path;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tie {
+    pub home_team: String,
+    pub away_team: String,
+    pub legs: Vec<MatchResult>,
+    pub advancing: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Round {
+    pub number: usize,
+    pub ties: Vec<Tie>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Bracket {
+    pub rounds: Vec<Round>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// A tie's round is the matchday of its first leg. The return leg, with home and
+/// away swapped, may follow in the same or any later matchday file.
+pub fn load_bracket(dir: &Path, away_goals: bool, options: &Options) -> Result<Bracket, Failure> {
+    let mut bracket = Bracket::default();
+    let mut ties: Vec<(usize, Tie)> = Vec::new();
+    for matchday in Matchdays::new(dir, options)?.knockout() {
+        let (day, mut parsed) = matchday?;
+        bracket.diagnostics.append(&mut parsed.diagnostics);
+        for result in parsed.results {
+            let return_leg = |(_, t): &&mut (usize, Tie)| {
+                t.legs.len() == 1
+                    && t.home_team == result.away_team
+                    && t.away_team == result.home_team
+            };
+            match ties.iter_mut().find(return_leg) {
+                Some((_, tie)) => tie.legs.push(result),
+                None => ties.push((
+                    day,
+                    Tie {
+                        home_team: result.home_team.clone(),
+                        away_team: result.away_team.clone(),
+                        legs: vec![result],
+                        advancing: None,
+                    },
+                )),
+            }
+        }
+    }
+    for (day, mut tie) in ties {
+        tie.advancing = tie.decide(away_goals);
+        match bracket.rounds.last_mut() {
+            Some(round) if round.number == day => round.ties.push(tie),
+            _ => bracket.rounds.push(Round {
+                number: day,
+                ties: vec![tie],
+            }),
+        }
+    }
+    Ok(bracket)
+}
+
+impl Tie {
+    fn goals(&self, team: &str) -> (u32, u32) {
+        self.legs.iter().fold((0, 0), |(total, away), leg| {
+            if leg.home_team == team {
+                (total + leg.home_goals as u32, away)
+            } else {
+                let goals = leg.away_goals as u32;
+                (total + goals, away + goals)
+            }
+        })
+    }
+
+    fn decide(&self, away_goals: bool) -> Option<String> {
+        let winner = |ordering: Ordering| match ordering {
+            Ordering::Greater => Some(self.home_team.clone()),
+            Ordering::Less => Some(self.away_team.clone()),
+            Ordering::Equal => None,
+        };
+        if let Some(forfeit) = self.legs.iter().find(|l| l.decision == Decision::Forfeit) {
+            return match forfeit.home_goals > forfeit.away_goals {
+                true => Some(forfeit.home_team.clone()),
+                false => Some(forfeit.away_team.clone()),
+            };
+        }
+        let (home_total, home_away) = self.goals(&self.home_team);
+        let (away_total, away_away) = self.goals(&self.away_team);
+        if let Some(team) = winner(home_total.cmp(&away_total)) {
+            return Some(team);
+        }
+        if away_goals && self.legs.len() > 1 {
+            if let Some(team) = winner(home_away.cmp(&away_away)) {
+                return Some(team);
+            }
+        }
+        match self.legs.last()?.decision {
+            Decision::Penalties { home, away } => match home > away {
+                true => Some(self.legs.last()?.home_team.clone()),
+                false => Some(self.legs.last()?.away_team.clone()),
+            },
+            _ => None,
+        }
+    }
+
+    pub fn aggregate(&self) -> (u32, u32) {
+        (self.goals(&self.home_team).0, self.goals(&self.away_team).0)
+    }
+}
+
+impl Bracket {
+    pub fn to_json(&self) -> String {
+        let rounds: Vec<String> = self
+            .rounds
+            .iter()
+            .map(|r| {
+                let ties: Vec<String> = r.ties.iter().map(tie_json).collect();
+                format!("{{\"round\":{},\"ties\":[{}]}}", r.number, ties.join(","))
+            })
+            .collect();
+        format!("[{}]\n", rounds.join(","))
+    }
+}
+
+fn tie_json(tie: &Tie) -> String {
+    let legs: Vec<String> = tie
+        .legs
+        .iter()
+        .map(|l| {
+            format!(
+                "{{\"home_team\":\"{}\",\"away_team\":\"{}\",\"home_goals\":{},\"away_goals\":{},\"result\":\"{}\"}}",
+                escape_json(&l.home_team),
+                escape_json(&l.away_team),
+                l.home_goals,
+                l.away_goals,
+                escape_json(&l.to_string())
+            )
+        })
+        .collect();
+    let advancing = match &tie.advancing {
+        Some(team) => format!("\"{}\"", escape_json(team)),
+        None => String::from("null"),
+    };
+    format!(
+        "{{\"home_team\":\"{}\",\"away_team\":\"{}\",\"legs\":[{}],\"advancing\":{}}}",
+        escape_json(&tie.home_team),
+        escape_json(&tie.away_team),
+        legs.join(","),
+        advancing
+    )
+}
+
+impl Display for Bracket {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        for round in &self.rounds {
+            f.write_fmt(format_args!("Round {}\n", round.number))?;
+            for tie in &round.ties {
+                let legs: Vec<String> = tie.legs.iter().map(|l| l.to_string()).collect();
+                let aggregate = match tie.legs.len() {
+                    1 => String::new(),
+                    _ => format!(" (agg. {}:{})", tie.aggregate().0, tie.aggregate().1),
+                };
+                f.write_fmt(format_args!(
+                    "  {}{} => {}\n",
+                    legs.join("; "),
+                    aggregate,
+                    tie.advancing.as_deref().unwrap_or("undecided")
+                ))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{result, temp_dir};
+
+    fn tie(legs: Vec<MatchResult>) -> Tie {
+        Tie {
+            home_team: legs[0].home_team.clone(),
+            away_team: legs[0].away_team.clone(),
+            legs,
+            advancing: None,
+        }
+    }
+
+    #[test]
+    fn decide_ties() {
+        let single = tie(vec![result("A", 2, 1, "B")]);
+        assert_eq!(single.decide(true), Some(String::from("A")));
+
+        let aggregate = tie(vec![result("A", 0, 1, "B"), result("B", 1, 3, "A")]);
+        assert_eq!(aggregate.decide(true), Some(String::from("A")));
+        assert_eq!(aggregate.aggregate(), (3, 2));
+
+        let away = tie(vec![result("A", 2, 2, "B"), result("B", 1, 1, "A")]);
+        assert_eq!(away.decide(true), Some(String::from("B")));
+        assert_eq!(away.decide(false), None);
+
+        let mut second = result("B", 1, 1, "A");
+        second.decision = Decision::Penalties { home: 2, away: 4 };
+        let penalties = tie(vec![result("A", 1, 1, "B"), second]);
+        assert_eq!(penalties.decide(true), Some(String::from("A")));
+
+        let mut second = result("B", 1, 0, "A");
+        second.decision = Decision::Penalties { home: 3, away: 4 };
+        let level = tie(vec![result("A", 2, 1, "B"), second]);
+        assert_eq!(level.aggregate(), (2, 2));
+        assert_eq!(level.decide(false), Some(String::from("A")));
+        assert_eq!(level.decide(true), Some(String::from("B")));
+    }
+
+    #[test]
+    fn load_and_render_bracket() {
+        let test_dir = temp_dir("soccer-table-test-bracket");
+        let path = test_dir.as_path();
+        std::fs::write(path.join("01.txt"), "A 1:0 B\nC 0:2 D\nB 2:0 A\nD 0:1 C\n").unwrap();
+        std::fs::write(path.join("02.txt"), "B 1:1 D 5:4 pen.\n").unwrap();
+
+        let bracket = load_bracket(path, true, &Options::default()).unwrap();
+        let advancing: Vec<Vec<Option<String>>> = bracket
+            .rounds
+            .iter()
+            .map(|r| r.ties.iter().map(|t| t.advancing.clone()).collect())
+            .collect();
+        assert_eq!(
+            advancing,
+            vec![
+                vec![Some(String::from("B")), Some(String::from("D"))],
+                vec![Some(String::from("B"))]
+            ]
+        );
+        let text = format!("{bracket}");
+        assert!(text.contains("  A 1:0 B; B 2:0 A (agg. 1:2) => B\n"));
+        assert!(text.contains("  B 1:1 D 5:4 pen. => B\n"));
+        let json = bracket.to_json();
+        assert!(json.starts_with("[{\"round\":1,\"ties\":[{\"home_team\":\"A\""));
+        assert!(json.contains("\"result\":\"B 1:1 D 5:4 pen.\"}],\"advancing\":\"B\"}"));
+    }
+
+    #[test]
+    fn pair_legs_across_matchdays() {
+        let test_dir = temp_dir("soccer-table-test-bracket-legs");
+        let path = test_dir.as_path();
+        std::fs::write(path.join("01.txt"), "A 1:0 B\nC 0:2 D\n").unwrap();
+        std::fs::write(path.join("02.txt"), "B 2:0 A\nD 0:1 C\n").unwrap();
+        std::fs::write(path.join("03.txt"), "B 1:1 D 5:4 pen.\n").unwrap();
+
+        let bracket = load_bracket(path, true, &Options::default()).unwrap();
+        let ties: Vec<(usize, usize, Option<&str>)> = bracket
+            .rounds
+            .iter()
+            .flat_map(|r| {
+                r.ties
+                    .iter()
+                    .map(|t| (r.number, t.legs.len(), t.advancing.as_deref()))
+            })
+            .collect();
+        assert_eq!(
+            ties,
+            vec![(1, 2, Some("B")), (1, 2, Some("D")), (3, 1, Some("B"))]
+        );
+    }
+}
@@ -1,61 +1,249 @@
+mod adjustments;
+mod aliases;
+mod fixtures;
+mod format;
+mod history;
+mod knockout;
 mod parsing;
+mod ratings;
+mod report;
+mod rules;
+mod scorers;
+mod season;
+mod server;
+mod simulation;
 mod table_row;
+mod watch;
+mod window;
 
+use adjustments::apply_adjustments;
 use itertools::Itertools;
-use parsing::{list_relevant_files, read_lines, MatchResult, ParseError};
+use parsing::{file_date, list_relevant_files, read_lines, MatchResult, Record};
+use std::cmp::Ordering;
 use std::collections::{hash_map::Entry, HashMap};
 use std::fmt::{Display, Error, Formatter};
-use std::path::Path;
-use table_row::TableRow;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use table_row::{RowError, TableRow};
 
+pub use adjustments::{load_adjustments, Adjustment};
+pub use aliases::Aliases;
+pub use fixtures::{load_fixtures, Projection, Projections};
+pub use format::TableFormat;
+pub use history::{compute_history, RankHistory};
+pub use knockout::{load_bracket, Bracket, Round, Tie};
+pub use parsing::{Decision, Diagnostic, Fixture, Goal, ParseError};
+pub use ratings::{compute_ratings, EloConfig, Ratings};
+pub use report::{HeadToHead, LogEntry, MatchLog};
+pub use rules::{PointsScheme, RankingRules, RulesError, TieBreaker};
+pub use scorers::{Scorer, Scorers};
+pub use season::{compute_season, BestPlaced, Competition, CompetitionTables, Group, Season};
+pub use server::{handle, route, serve, Response};
+pub use simulation::Simulation;
+pub use watch::Watcher;
+pub use window::{Date, Window};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Venue {
+    Home,
+    Away,
+}
+
+impl FromStr for Venue {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "home" => Ok(Venue::Home),
+            "away" => Ok(Venue::Away),
+            _ => Err(format!("unknown venue '{s}'")),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    pub rules: RankingRules,
+    pub lenient: bool,
+    pub aliases: Aliases,
+    pub adjustments: Vec<Adjustment>,
+    pub window: Window,
+}
+
+#[derive(Clone)]
 pub struct Table {
     rows: Vec<TableRow>,
+    home_rows: Vec<TableRow>,
+    away_rows: Vec<TableRow>,
+    results: Vec<MatchResult>,
+    rules: RankingRules,
+    venue: Option<Venue>,
+    form_length: usize,
+    postponed: Vec<Fixture>,
+    diagnostics: Vec<Diagnostic>,
+    names: Aliases,
+    adjustments: Vec<Adjustment>,
 }
 
 impl Table {
-    pub fn ranked(&self) -> Vec<TableRow> {
-        let mut rows = self.rows.clone();
-        rows.sort();
-        rows.iter_mut()
+    fn new(rows: Vec<TableRow>, results: Vec<MatchResult>, rules: RankingRules) -> Table {
+        Table {
+            rows,
+            home_rows: Vec::new(),
+            away_rows: Vec::new(),
+            results,
+            rules,
+            venue: None,
+            form_length: 0,
+            postponed: Vec::new(),
+            diagnostics: Vec::new(),
+            names: Aliases::default(),
+            adjustments: Vec::new(),
+        }
+    }
+
+    pub fn venue(&self, venue: Venue) -> Table {
+        let rows = match venue {
+            Venue::Home => self.home_rows.clone(),
+            Venue::Away => self.away_rows.clone(),
+        };
+        Table {
+            rows,
+            venue: Some(venue),
+            adjustments: Vec::new(),
+            ..self.clone()
+        }
+    }
+
+    pub fn with_form(self, form_length: usize) -> Table {
+        Table {
+            form_length,
+            ..self
+        }
+    }
+
+    pub fn form(&self, team: &str) -> String {
+        let played: Vec<char> = self
+            .results
+            .iter()
+            .filter_map(|r| {
+                let (scored, conceded) = match self.venue {
+                    Some(Venue::Home) | None if r.home_team == team => (r.home_goals, r.away_goals),
+                    Some(Venue::Away) | None if r.away_team == team => (r.away_goals, r.home_goals),
+                    _ => return None,
+                };
+                Some(match scored.cmp(&conceded) {
+                    Ordering::Greater => 'W',
+                    Ordering::Equal => 'D',
+                    Ordering::Less => 'L',
+                })
+            })
+            .collect();
+        let skip = played.len().saturating_sub(self.form_length);
+        played[skip..].iter().collect()
+    }
+
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    pub fn canonical(&self, team: &str) -> String {
+        self.names.canonical(team)
+    }
+
+    pub fn rare_teams(&self) -> Vec<String> {
+        let mut appearances: HashMap<&str, usize> = HashMap::new();
+        let played = self.results.iter().map(|r| (&r.home_team, &r.away_team));
+        let postponed = self.postponed.iter().map(|f| (&f.home_team, &f.away_team));
+        for (home, away) in played.chain(postponed) {
+            *appearances.entry(home).or_default() += 1;
+            *appearances.entry(away).or_default() += 1;
+        }
+        appearances
+            .into_iter()
+            .filter(|(_, n)| *n == 1)
+            .map(|(team, _)| team.to_string())
+            .sorted()
+            .collect()
+    }
+
+    /// Teams named in adjustments that do not appear in the table, most likely typos.
+    pub fn unknown_adjustments(&self, adjustments: &[Adjustment]) -> Vec<String> {
+        adjustments
+            .iter()
+            .map(|a| self.names.canonical(&a.team))
+            .filter(|team| self.rows.iter().all(|r| &r.team != team))
+            .sorted()
+            .dedup()
+            .collect()
+    }
+
+    pub fn ranked(&self) -> Result<Vec<TableRow>, Failure> {
+        let mut rows = self.rules.rank(&self.rows, &self.results)?;
+        Ok(rows
+            .iter_mut()
             .enumerate()
             .map(|(i, r)| {
-                r.rank = (i as u8) + 1;
+                r.rank = i + 1;
                 r.clone()
             })
-            .collect()
+            .collect())
     }
 }
 
 impl Display for Table {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
-        let title = format!(
-            "{:>3} {:30} {:>3} {:>3} {:>3} {:>3} {:>3} {:>3} {:>3}",
+        let mut title = format!(
+            "{:>3} {:30} {:>4} {:>4} {:>4} {:>4} {:>4} {:>4} {:>4}",
             "#", "Team", "P", "W", "T", "L", "+", "-", "="
         );
+        if self.form_length > 0 {
+            title.push_str(&format!(" {:w$}", "Form", w = self.form_length));
+        }
         let separator = "-".repeat(title.chars().count());
         f.write_str(&format!("{}\n", &title))?;
         f.write_fmt(format_args!("{}\n", separator))?;
-        let rows = self.ranked();
+        let rows = self.ranked().map_err(|_| Error)?;
         for r in rows {
+            let form = match self.form_length {
+                0 => String::new(),
+                _ => format!(" {}", self.form(&r.team)),
+            };
+            let markers: String = (1..=self.adjustments.len())
+                .filter(|i| self.adjustments[i - 1].team == r.team)
+                .map(|i| format!(" [{i}]"))
+                .collect();
             f.write_fmt(format_args!(
-                "{:>3} {:30} {:>3} {:>3} {:>3} {:>3} {:>3} {:>3} {:>3}\n",
+                "{:>3} {:30} {:>4} {:>4} {:>4} {:>4} {:>4} {:>4} {:>4}{}\n",
                 r.rank,
-                r.team,
+                format!("{}{markers}", r.team),
                 r.points,
                 r.wins,
                 r.ties,
                 r.defeats,
                 r.goals_scored,
                 r.goals_conceded,
-                r.goals_diff
+                r.goals_diff,
+                form
             ))?;
         }
+        if self.adjustments.is_empty() {
+            return Ok(());
+        }
+        f.write_str("\n")?;
+        for (i, adjustment) in self.adjustments.iter().enumerate() {
+            f.write_fmt(format_args!("[{}] {adjustment}\n", i + 1))?;
+        }
         Ok(())
     }
 }
 
+#[derive(Debug)]
 pub enum Failure {
     Parsing(String),
+    Diagnostics(Vec<Diagnostic>),
+    Io(String),
+    Overflow(String),
     Other(String),
 }
 
@@ -63,38 +251,224 @@ impl Display for Failure {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
         match self {
             Failure::Parsing(s) => write!(f, "{s}"),
+            Failure::Diagnostics(ds) => {
+                let lines: Vec<String> = ds.iter().map(|d| format!("{d}")).collect();
+                write!(f, "{}", lines.join("\n"))
+            }
+            Failure::Io(s) => write!(f, "{s}"),
+            Failure::Overflow(s) => write!(f, "{s}"),
             Failure::Other(s) => write!(f, "{s}"),
         }
     }
 }
 
-pub fn compute_table(dir: &Path, day: Option<usize>) -> Result<Table, Failure> {
-    let mut lines: Vec<String> = Vec::new();
-    if let Ok(files) = list_relevant_files(dir, day) {
-        for p in files {
-            lines.append(&mut read_lines(&p))
+impl From<RowError> for Failure {
+    fn from(err: RowError) -> Self {
+        match err {
+            RowError::Overflow { .. } => Failure::Overflow(format!("{err}")),
+            _ => Failure::Other(format!("{err}")),
         }
     }
+}
 
-    let result: Vec<Result<MatchResult, ParseError>> =
-        MatchResult::parse_all(lines).map_err(|e| Failure::Parsing(format!("{e}")))?;
-    let (results, errs): (Vec<_>, Vec<_>) = result.into_iter().partition_result();
-    if !errs.is_empty() {
-        let msg = errs
-            .iter()
-            .map(|e| format!("{}", e))
-            .collect::<Vec<_>>()
-            .join(", ");
-        return Err(Failure::Parsing(msg));
+pub fn compute_table(dir: &Path, day: Option<usize>, options: &Options) -> Result<Table, Failure> {
+    let files = select_files(dir, day, options)?;
+    let paths: Vec<PathBuf> = files.iter().map(|(_, f)| f.clone()).collect();
+    let mut names = options.aliases.clone();
+    let parsed = read_results(&paths, options.lenient, false, &mut names)?;
+    let day = files.last().map_or(0, |(d, _)| *d);
+    assemble_table(parsed.within(&options.window), names, options, day)
+}
+
+fn select_files(
+    dir: &Path,
+    day: Option<usize>,
+    options: &Options,
+) -> Result<Vec<(usize, PathBuf)>, Failure> {
+    let window = Window {
+        to_day: match (day, options.window.to_day) {
+            (Some(day), Some(to_day)) => Some(day.min(to_day)),
+            (day, to_day) => day.or(to_day),
+        },
+        ..options.window.clone()
+    };
+    list_relevant_files(dir, &window)
+        .map_err(|e| Failure::Io(format!("list {}: {e}", dir.display())))
+}
+
+fn assemble_table(
+    parsed: Parsed,
+    names: Aliases,
+    options: &Options,
+    day: usize,
+) -> Result<Table, Failure> {
+    let mut table = build_table(parsed.results, &options.rules)?;
+    let adjustments = apply_adjustments(&mut table.rows, &options.adjustments, &names, day)?;
+    Ok(Table {
+        postponed: parsed.postponed,
+        diagnostics: parsed.diagnostics,
+        names,
+        adjustments,
+        ..table
+    })
+}
+
+#[derive(Clone, Default)]
+struct Parsed {
+    results: Vec<MatchResult>,
+    postponed: Vec<Fixture>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Parsed {
+    fn extend(&mut self, other: Parsed) {
+        self.results.extend(other.results);
+        self.postponed.extend(other.postponed);
+        self.diagnostics.extend(other.diagnostics);
+    }
+
+    fn within(mut self, window: &Window) -> Parsed {
+        self.results.retain(|r| window.contains_date(r.date));
+        self
+    }
+
+    fn finish(self, lenient: bool, names: &mut Aliases) -> Result<Parsed, Failure> {
+        if !lenient && !self.diagnostics.is_empty() {
+            return Err(Failure::Diagnostics(self.diagnostics));
+        }
+        let results = self
+            .results
+            .into_iter()
+            .map(|result| MatchResult {
+                home_team: names.learn(&result.home_team),
+                away_team: names.learn(&result.away_team),
+                ..result
+            })
+            .collect();
+        let postponed = self
+            .postponed
+            .into_iter()
+            .map(|fixture| Fixture {
+                home_team: names.learn(&fixture.home_team),
+                away_team: names.learn(&fixture.away_team),
+            })
+            .collect();
+        Ok(Parsed {
+            results,
+            postponed,
+            diagnostics: self.diagnostics,
+        })
+    }
+}
+
+/// Reads a directory one matchday at a time, learning team names as they appear.
+struct Matchdays<'a> {
+    files: std::vec::IntoIter<(usize, PathBuf)>,
+    options: &'a Options,
+    names: Aliases,
+    knockout: bool,
+}
+
+impl<'a> Matchdays<'a> {
+    fn new(dir: &Path, options: &'a Options) -> Result<Matchdays<'a>, Failure> {
+        let files = list_relevant_files(dir, &options.window)
+            .map_err(|e| Failure::Io(format!("list {}: {e}", dir.display())))?;
+        Ok(Matchdays {
+            files: files.into_iter(),
+            options,
+            names: options.aliases.clone(),
+            knockout: false,
+        })
+    }
+
+    /// Reads the files as knockout rounds, see `MatchResult::parse_knockout`.
+    fn knockout(self) -> Matchdays<'a> {
+        Matchdays {
+            knockout: true,
+            ..self
+        }
     }
-    let single_rows: Vec<TableRow> = results
+
+    fn names(&self) -> &Aliases {
+        &self.names
+    }
+}
+
+impl Iterator for Matchdays<'_> {
+    type Item = Result<(usize, Parsed), Failure>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (day, file) = self.files.next()?;
+        let parsed = read_results(
+            &[file],
+            self.options.lenient,
+            self.knockout,
+            &mut self.names,
+        );
+        Some(parsed.map(|p| (day, p.within(&self.options.window))))
+    }
+}
+
+fn read_results(
+    files: &[PathBuf],
+    lenient: bool,
+    knockout: bool,
+    names: &mut Aliases,
+) -> Result<Parsed, Failure> {
+    let mut parsed = Parsed::default();
+    for file in files {
+        parsed.extend(read_file(file, knockout)?);
+    }
+    parsed.finish(lenient, names)
+}
+
+fn read_file(file: &Path, knockout: bool) -> Result<Parsed, Failure> {
+    let mut parsed = Parsed::default();
+    let lines =
+        read_lines(file).map_err(|e| Failure::Io(format!("read {}: {e}", file.display())))?;
+    let (numbers, lines): (Vec<usize>, Vec<String>) = lines.into_iter().unzip();
+    let records = match knockout {
+        true => MatchResult::parse_knockout(lines),
+        false => MatchResult::parse_all(lines),
+    }
+    .map_err(|e| Failure::Parsing(format!("{e}")))?;
+    for (line, record) in numbers.into_iter().zip(records) {
+        match record {
+            Ok(Record::Played(result)) => parsed.results.push(MatchResult {
+                date: result.date.or(file_date(file)),
+                ..result
+            }),
+            Ok(Record::Postponed(fixture)) => parsed.postponed.push(fixture),
+            Ok(Record::Ignored) => {}
+            Err(err) => parsed.diagnostics.push(Diagnostic {
+                path: file.to_path_buf(),
+                line,
+                err,
+            }),
+        }
+    }
+    Ok(parsed)
+}
+
+fn build_table(results: Vec<MatchResult>, rules: &RankingRules) -> Result<Table, Failure> {
+    let (home_rows, away_rows): (Vec<TableRow>, Vec<TableRow>) = results
         .iter()
-        .map(|r| TableRow::from(r.clone()))
-        .flat_map(|(a, b)| vec![a, b])
-        .collect();
+        .map(|r| TableRow::from_with_points(r.clone(), &rules.points))
+        .unzip();
+    let rows = aggregate(home_rows.iter().chain(away_rows.iter()).cloned().collect())?;
+    let teams: Vec<TableRow> = rows.iter().map(|r| TableRow::new(&r.team)).collect();
+    let home_rows = aggregate(teams.iter().cloned().chain(home_rows).collect())?;
+    let away_rows = aggregate(teams.into_iter().chain(away_rows).collect())?;
+    Ok(Table {
+        home_rows,
+        away_rows,
+        ..Table::new(rows, results, rules.clone())
+    })
+}
 
+fn aggregate(single_rows: Vec<TableRow>) -> Result<Vec<TableRow>, Failure> {
     let grouped = group_by_team(single_rows);
-    let res: Vec<Result<TableRow, String>> = grouped
+    let res: Vec<Result<TableRow, RowError>> = grouped
         .iter()
         .map(|(k, v)| {
             v.iter()
@@ -102,17 +476,18 @@ pub fn compute_table(dir: &Path, day: Option<usize>) -> Result<Table, Failure> {
         })
         .collect();
 
-    let mut rows: Vec<TableRow> = Vec::new();
-    let mut errs: Vec<String> = Vec::new();
-    for r in res {
-        match r {
-            Ok(row) => rows.push(row),
-            Err(err) => errs.push(err),
-        }
+    let (rows, errs): (Vec<_>, Vec<_>) = res.into_iter().partition_result();
+    if errs.is_empty() {
+        return Ok(rows);
     }
-    match errs.is_empty() {
-        true => Ok(Table { rows }),
-        false => Err(Failure::Other(errs.join(", "))),
+    let msg = errs
+        .iter()
+        .map(|e| format!("{}", e))
+        .collect::<Vec<_>>()
+        .join(", ");
+    match errs.iter().any(|e| matches!(e, RowError::Overflow { .. })) {
+        true => Err(Failure::Overflow(msg)),
+        false => Err(Failure::Other(msg)),
     }
 }
 
@@ -131,9 +506,46 @@ fn group_by_team(rows: Vec<TableRow>) -> HashMap<String, Vec<TableRow>> {
     rows_by_team
 }
 
+#[cfg(test)]
+mod test_support {
+    use crate::parsing::{Decision, Fixture, MatchResult};
+    use std::fs;
+    use std::path::PathBuf;
+
+    pub fn result(home: &str, home_goals: u16, away_goals: u16, away: &str) -> MatchResult {
+        MatchResult {
+            home_team: String::from(home),
+            away_team: String::from(away),
+            home_goals,
+            away_goals,
+            decision: Decision::Regular,
+            goals: Vec::new(),
+            date: None,
+        }
+    }
+
+    pub fn fixture(home: &str, away: &str) -> Fixture {
+        Fixture {
+            home_team: String::from(home),
+            away_team: String::from(away),
+        }
+    }
+
+    /// An empty directory below the system's temp dir, cleared of earlier runs' leftovers.
+    pub fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(name);
+        if dir.exists() {
+            fs::remove_dir_all(&dir).unwrap();
+        }
+        fs::create_dir(&dir).unwrap();
+        dir
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::{result, temp_dir};
 
     #[test]
     fn test_group_by_team() {
@@ -152,14 +564,197 @@ mod tests {
     }
 
     #[test]
-    fn test_ranking() {
-        let names = vec!["A", "B", "C", "D", "E"];
-        let table = Table {
-            rows: names.iter().map(|n| TableRow::new(n)).collect(),
+    fn test_aggregate_overflow() {
+        let mut a = TableRow::new("A");
+        a.points = i32::MAX;
+        let mut b = TableRow::new("A");
+        b.points = 3;
+        assert!(matches!(aggregate(vec![a, b]), Err(Failure::Overflow(_))));
+    }
+
+    #[test]
+    fn test_venue_tables() {
+        let results = vec![
+            result("A", 2, 0, "B"),
+            result("B", 1, 1, "A"),
+            result("A", 0, 1, "C"),
+        ];
+        let table = build_table(results, &RankingRules::default()).unwrap();
+        let home = table.venue(Venue::Home).ranked().unwrap();
+        let away = table.venue(Venue::Away).ranked().unwrap();
+        assert_eq!(home.len(), 3);
+        assert_eq!(away.len(), 3);
+        let a_home = home.iter().find(|r| r.team == "A").unwrap();
+        let a_away = away.iter().find(|r| r.team == "A").unwrap();
+        assert_eq!((a_home.wins, a_home.defeats, a_home.points), (1, 1, 3));
+        assert_eq!((a_away.ties, a_away.points), (1, 1));
+        let b_home = home.iter().find(|r| r.team == "B").unwrap();
+        assert_eq!((b_home.ties, b_home.goals_scored), (1, 1));
+    }
+
+    #[test]
+    fn test_form() {
+        let results = vec![
+            result("A", 2, 0, "B"),
+            result("B", 1, 1, "A"),
+            result("A", 0, 1, "C"),
+            result("C", 3, 0, "B"),
+        ];
+        let table = build_table(results, &RankingRules::default())
+            .unwrap()
+            .with_form(2);
+        assert_eq!(table.form("A"), "DL");
+        assert_eq!(table.form("B"), "DL");
+        assert_eq!(table.form("C"), "WW");
+        assert_eq!(table.venue(Venue::Home).form("A"), "WL");
+        assert_eq!(table.venue(Venue::Away).form("B"), "LL");
+    }
+
+    #[test]
+    fn test_collect_diagnostics() {
+        let test_dir = temp_dir("soccer-table-test-diagnostics");
+        let path = test_dir.as_path();
+        std::fs::write(path.join("01.txt"), "A 1:0 B\nC 2-2 D\n").unwrap();
+        std::fs::write(path.join("02.txt"), "B 2:0 C\nD 0:1\n").unwrap();
+
+        let strict = compute_table(path, None, &Options::default());
+        let Err(Failure::Diagnostics(diagnostics)) = strict else {
+            panic!("expected diagnostics");
+        };
+        let locations: Vec<(PathBuf, usize)> = diagnostics
+            .iter()
+            .map(|d| (d.path.clone(), d.line))
+            .collect();
+        assert_eq!(
+            locations,
+            vec![(path.join("01.txt"), 2), (path.join("02.txt"), 2)]
+        );
+
+        let options = Options {
+            lenient: true,
+            ..Options::default()
         };
-        let ranked = table.ranked();
+        let table = compute_table(path, None, &options).unwrap();
+        assert_eq!(table.diagnostics().len(), 2);
+        assert_eq!(table.ranked().unwrap().len(), 3);
+
+        let missing = compute_table(&path.join("missing"), None, &options);
+        assert!(matches!(missing, Err(Failure::Io(_))));
+    }
+
+    #[test]
+    fn test_adjustments() {
+        let test_dir = temp_dir("soccer-table-test-adjusted");
+        let path = test_dir.as_path();
+        std::fs::write(path.join("01.txt"), "A 1:0 B\n").unwrap();
+        std::fs::write(path.join("02.txt"), "B 1:1 A\n").unwrap();
+
+        let options = Options {
+            adjustments: vec![Adjustment {
+                team: String::from("A"),
+                points: -5,
+                reason: String::from("licence breach"),
+                from_day: 2,
+            }],
+            ..Options::default()
+        };
+        let first = compute_table(path, Some(1), &options).unwrap();
+        assert_eq!(first.ranked().unwrap()[0].team, "A");
+        let table = compute_table(path, None, &options).unwrap();
+        let ranked: Vec<(String, i32)> = table
+            .ranked()
+            .unwrap()
+            .into_iter()
+            .map(|r| (r.team, r.points))
+            .collect();
+        assert_eq!(
+            ranked,
+            vec![(String::from("B"), 1), (String::from("A"), -1)]
+        );
+        let text = format!("{table}");
+        assert!(text.contains("  2 A [1] "));
+        assert!(text.ends_with("\n[1] A: -5 points from matchday 2 (licence breach)\n"));
+
+        let typo = Adjustment {
+            team: String::from("Team Typo"),
+            ..options.adjustments[0].clone()
+        };
+        assert!(table.unknown_adjustments(&options.adjustments).is_empty());
+        assert_eq!(table.unknown_adjustments(&[typo]), vec!["Team Typo"]);
+    }
+
+    #[test]
+    fn test_calendar_window() {
+        let test_dir = temp_dir("soccer-table-test-calendar");
+        let path = test_dir.as_path();
+        std::fs::write(path.join("2024-08-17.txt"), "A 1:0 B\n").unwrap();
+        std::fs::write(path.join("2025-02-01.txt"), "B 2:0 A\n2025-02-05 C 0:1 A\n").unwrap();
+
+        let options = Options {
+            window: Window {
+                from_date: "2025-01-01".parse().ok(),
+                to_date: "2025-02-04".parse().ok(),
+                ..Window::default()
+            },
+            ..Options::default()
+        };
+        let table = compute_table(path, None, &options).unwrap();
+        let ranked: Vec<(String, i32)> = table
+            .ranked()
+            .unwrap()
+            .into_iter()
+            .map(|r| (r.team, r.points))
+            .collect();
+        assert_eq!(ranked, vec![(String::from("B"), 3), (String::from("A"), 0)]);
+        assert_eq!(
+            compute_table(path, Some(1), &Options::default())
+                .unwrap()
+                .results
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_aliases() {
+        let test_dir = temp_dir("soccer-table-test-aliases");
+        let path = test_dir.as_path();
+        std::fs::write(path.join("01.txt"), "FC Basel 1893 1:0 Young Boys\n").unwrap();
+        std::fs::write(
+            path.join("02.txt"),
+            "young  boys 2:0 fc  basel\nFC Zürich 1:1 FC Basel\n",
+        )
+        .unwrap();
+
+        let mut aliases = Aliases::default();
+        aliases.insert("FC Basel", ["FC Basel 1893"].into_iter());
+        let options = Options {
+            aliases,
+            ..Options::default()
+        };
+        let table = compute_table(path, None, &options).unwrap();
+        let teams: Vec<String> = table
+            .ranked()
+            .unwrap()
+            .into_iter()
+            .map(|r| r.team)
+            .collect();
+        assert_eq!(teams, vec!["FC Basel", "Young Boys", "FC Zürich"]);
+        assert_eq!(table.rare_teams(), vec!["FC Zürich"]);
+        assert_eq!(table.canonical("FC BASEL 1893"), "FC Basel");
+    }
+
+    #[test]
+    fn test_ranking() {
+        let names = ["A", "B", "C", "D", "E"];
+        let table = Table::new(
+            names.iter().map(|n| TableRow::new(n)).collect(),
+            Vec::new(),
+            RankingRules::default(),
+        );
+        let ranked = table.ranked().unwrap();
         for i in 0..ranked.len() {
-            assert_eq!(ranked.get(i).unwrap().rank as usize, i + 1);
+            assert_eq!(ranked.get(i).unwrap().rank, i + 1);
         }
     }
 }
@@ -1,30 +1,466 @@
-use soccer_table::compute_table;
-use std::env;
-use std::path::Path;
+use clap::{Args, CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use soccer_table::{
+    compute_history, compute_ratings, compute_season, compute_table, load_adjustments,
+    load_bracket, load_fixtures, serve, Aliases, Date, EloConfig, Failure, Options, RankingRules,
+    RulesError, Season, Table, TableFormat, Venue, Watcher, Window,
+};
+use std::io;
+use std::path::PathBuf;
 use std::process;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(
+    name = "soccer-table",
+    about = "Compute soccer tables from match results"
+)]
+struct Cli {
+    #[command(flatten)]
+    common: Common,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Args)]
+struct Common {
+    /// Ranking rules file
+    #[arg(long, global = true, value_name = "FILE")]
+    rules: Option<PathBuf>,
+
+    /// Canonical team names and their alternative spellings
+    #[arg(long, global = true, value_name = "FILE")]
+    aliases: Option<PathBuf>,
+
+    /// Point adjustments file
+    #[arg(long, global = true, value_name = "FILE")]
+    adjustments: Option<PathBuf>,
+
+    /// Skip malformed lines instead of failing
+    #[arg(long, global = true)]
+    lenient: bool,
+}
+
+#[derive(Args)]
+struct Range {
+    /// Directory containing the matchday files
+    dir: PathBuf,
+
+    #[command(flatten)]
+    bounds: Bounds,
+}
+
+#[derive(Args)]
+struct Bounds {
+    /// First matchday to take into account
+    #[arg(long, value_name = "DAY")]
+    from_day: Option<usize>,
+
+    /// Last matchday to take into account
+    #[arg(long, value_name = "DAY")]
+    to_day: Option<usize>,
+
+    /// Ignore matches played before this date
+    #[arg(long, value_name = "YYYY-MM-DD")]
+    from_date: Option<Date>,
+
+    /// Ignore matches played after this date
+    #[arg(long, value_name = "YYYY-MM-DD")]
+    to_date: Option<Date>,
+}
+
+impl Range {
+    fn window(&self) -> Window {
+        self.bounds.window()
+    }
+}
+
+impl Bounds {
+    fn window(&self) -> Window {
+        Window {
+            from_day: self.from_day,
+            to_day: self.to_day,
+            from_date: self.from_date,
+            to_date: self.to_date,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print the table
+    Table {
+        #[command(flatten)]
+        range: Range,
+        #[arg(long, default_value = "text")]
+        format: TableFormat,
+        #[arg(long)]
+        venue: Option<Venue>,
+        /// Number of recent results to show per team
+        #[arg(long, value_name = "N", default_value_t = 0)]
+        form: usize,
+        /// Reprint the table whenever a matchday file changes
+        #[arg(long)]
+        watch: bool,
+        /// Seconds between polls in watch mode
+        #[arg(long, value_name = "SECS", default_value_t = 1)]
+        interval: u64,
+    },
+    /// Print a team's match log, or its record against another team
+    Team {
+        team: String,
+        #[command(flatten)]
+        range: Range,
+        #[arg(long, value_name = "TEAM")]
+        against: Option<String>,
+    },
+    /// Print each team's rank after every matchday
+    History {
+        #[command(flatten)]
+        range: Range,
+        #[arg(long, default_value = "text")]
+        format: TableFormat,
+    },
+    /// Report every malformed line and suspicious team name
+    Validate {
+        #[command(flatten)]
+        range: Range,
+    },
+    /// Print the best and worst reachable positions
+    Projection {
+        #[command(flatten)]
+        range: Range,
+        #[arg(long, value_name = "FILE")]
+        fixtures: PathBuf,
+        #[arg(long, value_name = "N", default_value_t = 0)]
+        relegation: usize,
+    },
+    /// Simulate the remaining fixtures
+    Simulate {
+        #[command(flatten)]
+        range: Range,
+        #[arg(long, value_name = "FILE")]
+        fixtures: PathBuf,
+        #[arg(long, value_name = "N", default_value_t = 10000)]
+        runs: usize,
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Print the group tables of a season manifest
+    Season {
+        manifest: PathBuf,
+        #[command(flatten)]
+        bounds: Bounds,
+        #[arg(long, value_name = "N", default_value_t = 3)]
+        place: usize,
+        #[arg(long, default_value = "text")]
+        format: TableFormat,
+    },
+    /// Print a knockout bracket
+    Bracket {
+        #[command(flatten)]
+        range: Range,
+        #[arg(long)]
+        away_goals: bool,
+        #[arg(long, default_value = "text")]
+        format: TableFormat,
+    },
+    /// Print the top scorers
+    Scorers {
+        #[command(flatten)]
+        range: Range,
+        #[arg(long)]
+        team: Option<String>,
+    },
+    /// Print Elo ratings
+    Ratings {
+        #[command(flatten)]
+        range: Range,
+        #[arg(long, value_name = "K")]
+        k_factor: Option<f64>,
+        #[arg(long, value_name = "N")]
+        home_advantage: Option<f64>,
+        #[arg(long, default_value = "text")]
+        format: TableFormat,
+    },
+    /// Serve standings as JSON on localhost
+    Serve {
+        dir: PathBuf,
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+    },
+    /// Print shell completions
+    Completions { shell: Shell },
+}
 
 fn main() {
-    let mut args = env::args();
-    if args.len() < 2 {
-        eprintln!("usage: soccer-table DIR [DAY]");
-        process::exit(1);
-    }
-
-    let _bin = args.next().unwrap();
-    let dir = args.next().expect("missing DIR argument");
-    let day = match args.next() {
-        Some(day) => match day.parse::<usize>() {
-            Ok(day) => Some(day),
-            Err(e) => {
-                eprintln!("parsing day '{day}': {e}");
-                process::exit(1);
-            }
-        },
-        None => None,
+    let cli = Cli::parse();
+    if let Err(err) = run(cli) {
+        eprintln!("{err}");
+        process::exit(exit_code(&err));
+    }
+}
+
+// sysexits.h: EX_DATAERR, EX_IOERR, EX_SOFTWARE
+fn exit_code(err: &Failure) -> i32 {
+    match err {
+        Failure::Parsing(_) | Failure::Diagnostics(_) => 65,
+        Failure::Io(_) => 74,
+        Failure::Overflow(_) | Failure::Other(_) => 70,
+    }
+}
+
+fn run(cli: Cli) -> Result<(), Failure> {
+    let options = load_options(&cli.common)?;
+    match cli.command {
+        Command::Table {
+            range,
+            format,
+            venue,
+            form,
+            watch,
+            interval,
+        } => {
+            let print = |table: Table| {
+                let table = match venue {
+                    Some(venue) => table.venue(venue),
+                    None => table,
+                };
+                print!("{}", format.render(&table.with_form(form))?);
+                Ok::<(), Failure>(())
+            };
+            if watch {
+                let options = with_range(&options, &range);
+                let mut watcher = Watcher::new(&range.dir, None, &options);
+                loop {
+                    match watcher.poll() {
+                        Ok(Some(table)) => {
+                            for d in table.diagnostics() {
+                                eprintln!("skipped {d}");
+                            }
+                            match print(table) {
+                                Ok(()) => println!(),
+                                Err(err) => eprintln!("{err}"),
+                            }
+                        }
+                        Ok(None) => {}
+                        Err(err) => eprintln!("{err}"),
+                    }
+                    thread::sleep(Duration::from_secs(interval));
+                }
+            }
+            print(table(&range, &options)?)?;
+        }
+        Command::Team {
+            team,
+            range,
+            against,
+        } => {
+            let table = table(&range, &options)?;
+            match against {
+                Some(other) => print!("{}", table.head_to_head(&team, &other)),
+                None => print!("{}", table.match_log(&team)),
+            }
+        }
+        Command::History { range, format } => {
+            let history = compute_history(&range.dir, &with_range(&options, &range))?;
+            for d in &history.diagnostics {
+                eprintln!("skipped {d}");
+            }
+            match format {
+                TableFormat::Text => print!("{history}"),
+                TableFormat::Csv => print!("{}", history.to_csv()),
+                _ => return Err(unsupported("history", "text and csv")),
+            }
+        }
+        Command::Validate { range } => {
+            let options = Options {
+                lenient: true,
+                ..with_range(&options, &range)
+            };
+            let table = compute_table(&range.dir, None, &options)?;
+            for team in table.rare_teams() {
+                eprintln!("warning: team '{team}' appears only once");
+            }
+            for team in table.unknown_adjustments(&options.adjustments) {
+                eprintln!("warning: adjustment for team '{team}' matches no team in the table");
+            }
+            if !table.diagnostics().is_empty() {
+                return Err(Failure::Diagnostics(table.diagnostics().to_vec()));
+            }
+        }
+        Command::Projection {
+            range,
+            fixtures,
+            relegation,
+        } => {
+            let table = table(&range, &options)?;
+            let schedule = load_fixtures(&fixtures)?;
+            let projections = table.projections(&schedule, relegation)?;
+            print!("{projections}");
+            println!();
+            for p in &projections.rows {
+                let remaining: Vec<String> = table
+                    .remaining_for(&p.team, &schedule)
+                    .iter()
+                    .map(|f| format!("{} - {}", f.home_team, f.away_team))
+                    .collect();
+                println!("{}: {}", p.team, remaining.join(", "));
+            }
+        }
+        Command::Simulate {
+            range,
+            fixtures,
+            runs,
+            seed,
+        } => {
+            let table = table(&range, &options)?;
+            let schedule = load_fixtures(&fixtures)?;
+            let seed = seed.unwrap_or_else(rand::random);
+            let simulation = table.simulate(&schedule, runs, seed)?;
+            eprintln!("{} runs with seed {}", simulation.runs, simulation.seed);
+            print!("{simulation}");
+        }
+        Command::Season {
+            manifest,
+            bounds,
+            place,
+            format,
+        } => {
+            let season = Season::load(&manifest)?;
+            let options = Options {
+                window: bounds.window(),
+                ..options
+            };
+            for competition in &compute_season(&season, None, &options)? {
+                for (group, table) in &competition.groups {
+                    for d in table.diagnostics() {
+                        eprintln!("skipped {d}");
+                    }
+                    println!("{} - {}", competition.name, group);
+                    print!("{}", format.render(table)?);
+                    println!();
+                }
+                if competition.groups.len() > 1 {
+                    println!("{} - best teams placed {}", competition.name, place);
+                    print!("{}", competition.best_placed(place)?);
+                    println!();
+                }
+            }
+        }
+        Command::Bracket {
+            range,
+            away_goals,
+            format,
+        } => {
+            let bracket = load_bracket(&range.dir, away_goals, &with_range(&options, &range))?;
+            for d in &bracket.diagnostics {
+                eprintln!("skipped {d}");
+            }
+            match format {
+                TableFormat::Text => print!("{bracket}"),
+                TableFormat::Json => print!("{}", bracket.to_json()),
+                _ => return Err(unsupported("bracket", "text and json")),
+            }
+        }
+        Command::Scorers { range, team } => {
+            let table = table(&range, &options)?;
+            match team {
+                Some(team) => print!("{}", table.team_scorers(&team)),
+                None => print!("{}", table.scorers()),
+            }
+        }
+        Command::Ratings {
+            range,
+            k_factor,
+            home_advantage,
+            format,
+        } => {
+            let default = EloConfig::default();
+            let elo = EloConfig {
+                k_factor: k_factor.unwrap_or(default.k_factor),
+                home_advantage: home_advantage.unwrap_or(default.home_advantage),
+                ..default
+            };
+            let ratings = compute_ratings(&range.dir, &with_range(&options, &range), &elo)?;
+            for d in &ratings.diagnostics {
+                eprintln!("skipped {d}");
+            }
+            match format {
+                TableFormat::Text => print!("{ratings}"),
+                TableFormat::Csv => print!("{}", ratings.to_csv()),
+                _ => return Err(unsupported("ratings", "text and csv")),
+            }
+        }
+        Command::Serve { dir, port } => {
+            eprintln!("serving {} on http://127.0.0.1:{port}", dir.display());
+            serve(port, &dir, &options)
+                .map_err(|e| Failure::Io(format!("serve on port {port}: {e}")))?;
+        }
+        Command::Completions { shell } => {
+            clap_complete::generate(
+                shell,
+                &mut Cli::command(),
+                "soccer-table",
+                &mut io::stdout(),
+            );
+        }
+    }
+    Ok(())
+}
+
+fn load_options(common: &Common) -> Result<Options, Failure> {
+    let rules = match &common.rules {
+        Some(file) => RankingRules::load(file).map_err(|e| match e {
+            RulesError::Reading { .. } => Failure::Io(format!("{e}")),
+            _ => Failure::Parsing(format!("{}: {e}", file.display())),
+        })?,
+        None => RankingRules::default(),
+    };
+    let aliases = match &common.aliases {
+        Some(file) => Aliases::load(file)?,
+        None => Aliases::default(),
+    };
+    let adjustments = match &common.adjustments {
+        Some(file) => load_adjustments(file)?,
+        None => Vec::new(),
     };
+    Ok(Options {
+        rules,
+        lenient: common.lenient,
+        aliases,
+        adjustments,
+        window: Window::default(),
+    })
+}
+
+fn with_range(options: &Options, range: &Range) -> Options {
+    Options {
+        window: range.window(),
+        ..options.clone()
+    }
+}
 
-    match compute_table(Path::new(&dir), day) {
-        Ok(table) => println!("{}", table),
-        Err(err) => eprintln!("{}", err),
+fn table(range: &Range, options: &Options) -> Result<Table, Failure> {
+    let table = compute_table(&range.dir, None, &with_range(options, range))?;
+    for d in table.diagnostics() {
+        eprintln!("skipped {d}");
     }
+    if range.window().is_unbounded() {
+        for team in table.rare_teams() {
+            eprintln!(
+                "warning: team '{team}' appears only once, check for a typo or missing alias"
+            );
+        }
+        for team in table.unknown_adjustments(&options.adjustments) {
+            eprintln!("warning: adjustment for team '{team}' matches no team in the table");
+        }
+    }
+    Ok(table)
+}
+
+fn unsupported(command: &str, formats: &str) -> Failure {
+    Failure::Other(format!("{command} only supports {formats} output"))
 }
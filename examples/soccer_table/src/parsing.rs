@@ -1,8 +1,11 @@
+use crate::window::{Date, Window};
+use crate::Venue;
 use regex::Regex;
 use std::error::Error;
 use std::fmt;
 use std::fmt::{Display, Formatter};
 use std::fs;
+use std::io;
 use std::num::ParseIntError;
 use std::path::{Path, PathBuf};
 
@@ -10,15 +13,52 @@ use std::path::{Path, PathBuf};
 pub struct MatchResult {
     pub home_team: String,
     pub away_team: String,
-    pub home_goals: u8,
-    pub away_goals: u8,
+    pub home_goals: u16,
+    pub away_goals: u16,
+    pub decision: Decision,
+    pub goals: Vec<Goal>,
+    pub date: Option<Date>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Goal {
+    pub player: String,
+    pub side: Venue,
+    pub minute: u16,
+    pub added: u16,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Decision {
+    #[default]
+    Regular,
+    ExtraTime,
+    Penalties {
+        home: u16,
+        away: u16,
+    },
+    Forfeit,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fixture {
+    pub home_team: String,
+    pub away_team: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Record {
+    Played(MatchResult),
+    Postponed(Fixture),
+    Ignored,
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub enum ParseError {
     RegexSyntax { pat: String, err: regex::Error },
     RegexMismatch { pat: String, val: String },
     NumberParsing { val: String, err: ParseIntError },
+    Inconsistent { val: String, reason: &'static str },
 }
 
 impl Error for ParseError {}
@@ -29,90 +69,306 @@ impl Display for ParseError {
             ParseError::RegexSyntax { pat: p, err: e } => write!(f, "invalid regex {p}: {e}"),
             ParseError::RegexMismatch { pat: p, val: s } => write!(f, "{s} did not match {p}"),
             ParseError::NumberParsing { val: v, err: e } => write!(f, "parse {v}: {e}"),
+            ParseError::Inconsistent { val: v, reason: r } => write!(f, "{v}: {r}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    pub path: PathBuf,
+    pub line: usize,
+    pub err: ParseError,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.path.display(), self.line, self.err)
+    }
+}
+
+impl Display for MatchResult {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if let Some(date) = self.date {
+            write!(f, "{date} ")?;
+        }
+        write!(
+            f,
+            "{} {}:{} {}",
+            self.home_team, self.home_goals, self.away_goals, self.away_team
+        )?;
+        match self.decision {
+            Decision::Regular => Ok(()),
+            Decision::ExtraTime => write!(f, " a.e.t."),
+            Decision::Penalties { home, away } => write!(f, " {home}:{away} pen."),
+            Decision::Forfeit => write!(f, " w.o."),
+        }?;
+        if self.goals.is_empty() {
+            return Ok(());
+        }
+        let side = |venue: Venue| {
+            let mut scorers: Vec<String> = Vec::new();
+            let mut last: Option<&str> = None;
+            for goal in self.goals.iter().filter(|g| g.side == venue) {
+                match last == Some(goal.player.as_str()) {
+                    true => scorers.push(format!("{goal}")),
+                    false => scorers.push(format!("{} {goal}", goal.player)),
+                }
+                last = Some(&goal.player);
+            }
+            scorers.join(", ")
+        };
+        let scorers = format!("{}; {}", side(Venue::Home), side(Venue::Away));
+        write!(f, " ({})", scorers.trim())
+    }
+}
+
+impl Display for Goal {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.added {
+            0 => write!(f, "{}'", self.minute),
+            added => write!(f, "{}+{added}'", self.minute),
         }
     }
 }
 
+const RESULT_PATTERN: &str = concat!(
+    r"^(?:(?<date>[0-9]{4}-[0-9]{2}-[0-9]{2}) )?(?<home>.+?) (?:(?<hg>[0-9]+):(?<ag>[0-9]+)|(?<postponed>-:-)) (?<away>.+?)",
+    r"(?: (?:(?<wo>w\.o\.)|(?<aet>a\.e\.t\.)|(?<hp>[0-9]+):(?<ap>[0-9]+) pen\.))?",
+    // only a group of minutes counts as scorers, anything else like "(U21)" belongs to the away team
+    r"(?: \((?<scorers>(?:;\s*)?[^,;()]*?[0-9]+(?:\+[0-9]+)?'",
+    r"(?:\s*[,;]\s*[^,;()]*?[0-9]+(?:\+[0-9]+)?')*\s*;?)\))?$"
+);
+
 impl MatchResult {
-    pub fn parse_all(
+    pub fn parse_all(lines: Vec<String>) -> Result<Vec<Result<Record, ParseError>>, ParseError> {
+        Self::parse_lines(lines, false)
+    }
+
+    /// Like `parse_all`, but also accepts a shoot-out after a decided second leg.
+    pub fn parse_knockout(
+        lines: Vec<String>,
+    ) -> Result<Vec<Result<Record, ParseError>>, ParseError> {
+        Self::parse_lines(lines, true)
+    }
+
+    fn parse_lines(
         lines: Vec<String>,
-    ) -> Result<Vec<Result<MatchResult, ParseError>>, ParseError> {
-        let pattern = "^(.+) ([0-9]+):([0-9]+) (.+)$";
-        match Regex::new(pattern) {
-            Ok(p) => Ok(lines.iter().map(|l| Self::parse(l.into(), &p)).collect()),
+        knockout: bool,
+    ) -> Result<Vec<Result<Record, ParseError>>, ParseError> {
+        match Regex::new(RESULT_PATTERN) {
+            Ok(p) => Ok(lines
+                .iter()
+                .map(|l| Self::parse(l.into(), &p, knockout))
+                .collect()),
             Err(e) => Err(ParseError::RegexSyntax {
-                pat: pattern.into(),
+                pat: RESULT_PATTERN.into(),
                 err: e,
             }),
         }
     }
 
-    fn parse(line: String, pattern: &Regex) -> Result<MatchResult, ParseError> {
-        let [ht, hg, ag, at] = pattern
-            .captures_iter(&line)
-            .map(|c| c.extract::<4>())
-            .flat_map(|(_, matches)| matches)
-            .collect::<Vec<&str>>()
-            .try_into()
-            .or(Err(ParseError::RegexMismatch {
-                pat: pattern.as_str().into(),
-                val: line.clone(),
-            }))?;
-        let home_team = ht.to_string();
-        let away_team = at.to_string();
-        let home_goals = hg.parse::<u8>().map_err(|e| ParseError::NumberParsing {
-            val: hg.into(),
-            err: e,
-        })?;
-        let away_goals = ag.parse::<u8>().map_err(|e| ParseError::NumberParsing {
-            val: ag.into(),
-            err: e,
+    fn parse(line: String, pattern: &Regex, knockout: bool) -> Result<Record, ParseError> {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            return Ok(Record::Ignored);
+        }
+        let captures = pattern.captures(trimmed).ok_or(ParseError::RegexMismatch {
+            pat: pattern.as_str().into(),
+            val: line.clone(),
         })?;
-        Ok(MatchResult {
-            home_team,
-            away_team,
-            home_goals,
-            away_goals,
-        })
+        let home_team = captures["home"].to_string();
+        let away_team = captures["away"].to_string();
+        let date = match captures.name("date") {
+            Some(date) => {
+                Some(
+                    date.as_str()
+                        .parse::<Date>()
+                        .map_err(|_| ParseError::Inconsistent {
+                            val: line.clone(),
+                            reason: "the date is not a valid calendar date",
+                        })?,
+                )
+            }
+            None => None,
+        };
+        if captures.name("postponed").is_some() {
+            return Ok(Record::Postponed(Fixture {
+                home_team,
+                away_team,
+            }));
+        }
+        let number = |name: &str| {
+            let val = &captures[name];
+            val.parse::<u16>().map_err(|e| ParseError::NumberParsing {
+                val: val.into(),
+                err: e,
+            })
+        };
+        let home_goals = number("hg")?;
+        let away_goals = number("ag")?;
+        let inconsistent = |reason| ParseError::Inconsistent {
+            val: line.clone(),
+            reason,
+        };
+        let decision = if captures.name("wo").is_some() {
+            Decision::Forfeit
+        } else if captures.name("aet").is_some() {
+            Decision::ExtraTime
+        } else if captures.name("hp").is_some() {
+            Decision::Penalties {
+                home: number("hp")?,
+                away: number("ap")?,
+            }
+        } else {
+            Decision::Regular
+        };
+        match decision {
+            Decision::Forfeit | Decision::ExtraTime if home_goals == away_goals => {
+                Err(inconsistent("a forfeit or extra time requires a winner"))
+            }
+            Decision::Penalties { .. } if !knockout && home_goals != away_goals => {
+                Err(inconsistent("a penalty shoot-out requires a tied score"))
+            }
+            Decision::Penalties { home, away } if home == away => {
+                Err(inconsistent("a penalty shoot-out requires a winner"))
+            }
+            decision => {
+                let goals = match captures.name("scorers") {
+                    Some(scorers) => parse_scorers(scorers.as_str(), &line)?,
+                    None => Vec::new(),
+                };
+                let scored = |side| goals.iter().filter(|g| g.side == side).count();
+                if !goals.is_empty()
+                    && (scored(Venue::Home) != home_goals as usize
+                        || scored(Venue::Away) != away_goals as usize)
+                {
+                    return Err(inconsistent("the scorers do not add up to the score"));
+                }
+                Ok(Record::Played(MatchResult {
+                    home_team,
+                    away_team,
+                    home_goals,
+                    away_goals,
+                    decision,
+                    goals,
+                    date,
+                }))
+            }
+        }
     }
 }
 
-pub fn list_relevant_files(dir: &Path, day: Option<usize>) -> Result<Vec<PathBuf>, ()> {
-    let pattern = Regex::new("([0-9]+).txt$").or(Err(()))?;
-    match list_files(dir) {
-        Ok(mut files) => {
-            files.sort();
-            match day {
-                Some(day) => {
-                    let days: Vec<usize> = files
-                        .iter()
-                        .map(|f| extract_day(f, &pattern))
-                        .map(|o| o.unwrap_or(usize::MAX))
-                        .take_while(|d| *d <= day)
-                        .collect();
-                    Ok(files.iter().take(days.len()).map(PathBuf::from).collect())
-                }
-                None => Ok(files),
+fn parse_scorers(annotation: &str, line: &str) -> Result<Vec<Goal>, ParseError> {
+    let malformed = || ParseError::Inconsistent {
+        val: line.into(),
+        reason: "scorers must be given as 'Name 12', 40'; Name 88''",
+    };
+    let (home, away) = annotation.split_once(';').unwrap_or((annotation, ""));
+    if away.contains(';') {
+        return Err(malformed());
+    }
+    let mut goals: Vec<Goal> = Vec::new();
+    for (side, scorers) in [(Venue::Home, home), (Venue::Away, away)] {
+        let mut player: Option<String> = None;
+        for token in scorers
+            .split(',')
+            .map(|t| t.trim())
+            .filter(|t| !t.is_empty())
+        {
+            let (name, minute) = match token.rsplit_once(' ') {
+                Some((name, minute)) => (Some(name.trim().to_string()), minute),
+                None => (None, token),
+            };
+            if name
+                .as_deref()
+                .is_some_and(|n| n.split_whitespace().any(is_minute))
+            {
+                return Err(malformed());
             }
+            player = name.or(player);
+            let (Some(player), Some(minute)) = (&player, minute.strip_suffix('\'')) else {
+                return Err(malformed());
+            };
+            let (minute, added) = minute.split_once('+').unwrap_or((minute, "0"));
+            let number = |val: &str| {
+                val.parse::<u16>().map_err(|e| ParseError::NumberParsing {
+                    val: val.into(),
+                    err: e,
+                })
+            };
+            goals.push(Goal {
+                player: player.clone(),
+                side,
+                minute: number(minute)?,
+                added: number(added)?,
+            });
         }
-        Err(()) => Err(()),
     }
+    Ok(goals)
+}
+
+/// A token like `12'` or `45+2'`, which would be swallowed into a scorer's name.
+fn is_minute(token: &str) -> bool {
+    token.strip_suffix('\'').is_some_and(|m| {
+        m.split('+')
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+    })
 }
 
-pub fn read_lines(file: &Path) -> Vec<String> {
-    match fs::read_to_string(file) {
-        Ok(s) => s.trim().split('\n').map(|s| s.trim().to_string()).collect(),
-        Err(_) => Vec::new(),
+/// Numbered files (`03.txt`) count as that matchday, date-named files
+/// (`2024-08-17.txt`) by their position in calendar order. Other files are ignored.
+pub fn list_relevant_files(
+    dir: &Path,
+    window: &Window,
+) -> Result<Vec<(usize, PathBuf)>, io::Error> {
+    let pattern = Regex::new(r"^([0-9]+)\.txt$").map_err(io::Error::other)?;
+    let number = |f: &Path| extract_day(f, &pattern);
+    let dated = |f: &Path| f.extension().is_some_and(|e| e == "txt") && file_date(f).is_some();
+    let mut files: Vec<PathBuf> = list_files(dir)?
+        .into_iter()
+        .filter(|f| number(f).is_some() || dated(f))
+        .collect();
+    files.sort_by_key(|f| (number(f).unwrap_or(usize::MAX), f.clone()));
+    Ok(files
+        .into_iter()
+        .enumerate()
+        .map(|(i, f)| (number(&f).unwrap_or(i + 1), f))
+        .filter(|(d, f)| {
+            window.contains_day(*d)
+                && file_date(f).is_none_or(|date| window.contains_date(Some(date)))
+        })
+        .collect())
+}
+
+pub fn file_date(file: &Path) -> Option<Date> {
+    file.file_stem()?.to_str()?.parse::<Date>().ok()
+}
+
+pub fn read_lines(file: &Path) -> Result<Vec<(usize, String)>, io::Error> {
+    let content = fs::read_to_string(file)?;
+    let lines: Vec<(usize, String)> = content
+        .lines()
+        .enumerate()
+        .map(|(i, l)| (i + 1, l.trim().to_string()))
+        .collect();
+    let first = lines.iter().position(|(_, l)| !l.is_empty());
+    let last = lines.iter().rposition(|(_, l)| !l.is_empty());
+    match (first, last) {
+        (Some(first), Some(last)) => Ok(lines[first..=last].to_vec()),
+        _ => Ok(Vec::new()),
     }
 }
 
-fn list_files(dir: &Path) -> Result<Vec<PathBuf>, ()> {
-    if !dir.exists() || !dir.is_dir() {
-        return Err(());
+fn list_files(dir: &Path) -> Result<Vec<PathBuf>, io::Error> {
+    if !dir.is_dir() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{} is not a directory", dir.display()),
+        ));
     }
     let mut files: Vec<_> = Vec::new();
-    let entries = dir.read_dir().or(Err(()))?;
-    for entry in entries.flatten() {
+    for entry in dir.read_dir()?.flatten() {
         if let Ok(t) = entry.file_type() {
             if t.is_file() {
                 files.push(dir.join(entry.path()));
@@ -123,7 +379,7 @@ fn list_files(dir: &Path) -> Result<Vec<PathBuf>, ()> {
 }
 
 fn extract_day(file: &Path, pattern: &Regex) -> Option<usize> {
-    let file_name = file.to_str().unwrap_or("");
+    let file_name = file.file_name()?.to_str()?;
     pattern
         .captures(file_name)
         .and_then(|c| c.get(1).map(|m| m.as_str()))
@@ -133,22 +389,28 @@ fn extract_day(file: &Path, pattern: &Regex) -> Option<usize> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::env;
+    use crate::test_support::temp_dir;
     use std::fs;
 
+    fn paths(dir: &Path, window: &Window) -> Result<Vec<PathBuf>, io::Error> {
+        let files = list_relevant_files(dir, window)?;
+        Ok(files.into_iter().map(|(_, f)| f).collect())
+    }
+
+    fn up_to(day: usize) -> Window {
+        Window {
+            to_day: Some(day),
+            ..Window::default()
+        }
+    }
+
     #[test]
     fn must_list_relevant_files() {
         let relevant_day = 50;
         let total_days = 99;
 
-        let mut test_dir = PathBuf::from(env::temp_dir());
-        test_dir.push("soccer-table-test");
+        let test_dir = temp_dir("soccer-table-test");
         let path = test_dir.as_path();
-        if path.exists() {
-            fs::remove_dir_all(path).unwrap();
-        }
-
-        fs::create_dir(path).unwrap();
         let mut relevant_days: Vec<PathBuf> = Vec::new();
         let mut all_days: Vec<PathBuf> = Vec::new();
         for i in 1..=total_days {
@@ -162,15 +424,90 @@ mod tests {
             all_days.push(path_buf);
         }
 
-        let mut actual = list_relevant_files(path, Some(relevant_day)).unwrap();
+        let mut actual = paths(path, &up_to(relevant_day)).unwrap();
         actual.sort();
         assert_eq!(actual, relevant_days);
 
-        let mut actual = list_relevant_files(path, None).unwrap();
+        let mut actual = paths(path, &Window::default()).unwrap();
         actual.sort();
         assert_eq!(actual, all_days);
     }
 
+    #[test]
+    fn must_order_files_by_day() {
+        let test_dir = temp_dir("soccer-table-test-order");
+        let path = test_dir.as_path();
+        for name in [
+            "10.txt",
+            "2.txt",
+            "1.txt",
+            "README.md",
+            "aliases.txt",
+            "1Atxt",
+        ] {
+            fs::File::create_new(path.join(name)).unwrap();
+        }
+        let expected: Vec<PathBuf> = ["1.txt", "2.txt", "10.txt"]
+            .iter()
+            .map(|n| path.join(n))
+            .collect();
+        assert_eq!(paths(path, &Window::default()).unwrap(), expected);
+        assert_eq!(paths(path, &up_to(2)).unwrap(), expected[..2]);
+    }
+
+    #[test]
+    fn must_select_files_by_window() {
+        let test_dir = temp_dir("soccer-table-test-window");
+        let path = test_dir.as_path();
+        for name in [
+            "2024-09-14.txt",
+            "2024-08-17.txt",
+            "2024-08-24.txt",
+            "notes.txt",
+        ] {
+            fs::File::create_new(path.join(name)).unwrap();
+        }
+        let days: Vec<usize> = list_relevant_files(path, &Window::default())
+            .unwrap()
+            .into_iter()
+            .map(|(d, _)| d)
+            .collect();
+        assert_eq!(days, vec![1, 2, 3]);
+
+        let window = Window {
+            from_day: Some(2),
+            ..Window::default()
+        };
+        let expected = vec![path.join("2024-08-24.txt"), path.join("2024-09-14.txt")];
+        assert_eq!(paths(path, &window).unwrap(), expected);
+
+        let window = Window {
+            to_date: "2024-08-31".parse().ok(),
+            ..Window::default()
+        };
+        assert_eq!(
+            paths(path, &window).unwrap(),
+            vec![path.join("2024-08-17.txt"), path.join("2024-08-24.txt")]
+        );
+    }
+
+    #[test]
+    fn must_read_numbered_lines() {
+        let test_dir = temp_dir("soccer-table-test-lines");
+        let path = test_dir.join("01.txt");
+        fs::write(&path, "\n  A 1:0 B \n\nC 2:2 D\n\n").unwrap();
+        let expected = vec![
+            (2, String::from("A 1:0 B")),
+            (3, String::new()),
+            (4, String::from("C 2:2 D")),
+        ];
+        assert_eq!(read_lines(&path).unwrap(), expected);
+
+        let path = test_dir.join("missing.txt");
+        assert!(read_lines(&path).is_err());
+        assert!(paths(&path, &Window::default()).is_err());
+    }
+
     #[test]
     fn must_parse_result() {
         let raw = String::from("The Rustaceans 3:2 COBOL FC 1958");
@@ -179,10 +516,129 @@ mod tests {
             away_team: String::from("COBOL FC 1958"),
             home_goals: 3,
             away_goals: 2,
+            decision: Decision::Regular,
+            goals: Vec::new(),
+            date: None,
         };
         let parsed = MatchResult::parse_all(vec![raw]).unwrap();
-        let result = parsed.get(0).unwrap();
+        let result = parsed.first().unwrap();
         let actual = result.as_ref().unwrap();
-        assert_eq!(*actual, expected);
+        assert_eq!(*actual, Record::Played(expected));
+    }
+
+    #[test]
+    fn must_parse_extended_grammar() {
+        let raw = [
+            "# matchday 1",
+            "",
+            "FC Rustaceans 3:0 COBOL FC 1958 w.o.",
+            "FC Rustaceans 2:1 COBOL FC 1958 a.e.t.",
+            "FC Rustaceans 1:1 COBOL FC 1958 4:3 pen.",
+            "FC Rustaceans -:- COBOL FC 1958",
+        ];
+        let parsed: Vec<Record> =
+            MatchResult::parse_all(raw.iter().map(|l| l.to_string()).collect())
+                .unwrap()
+                .into_iter()
+                .map(|r| r.unwrap())
+                .collect();
+        let result = |home_goals, away_goals, decision| {
+            Record::Played(MatchResult {
+                home_team: String::from("FC Rustaceans"),
+                away_team: String::from("COBOL FC 1958"),
+                home_goals,
+                away_goals,
+                decision,
+                goals: Vec::new(),
+                date: None,
+            })
+        };
+        let expected = vec![
+            Record::Ignored,
+            Record::Ignored,
+            result(3, 0, Decision::Forfeit),
+            result(2, 1, Decision::ExtraTime),
+            result(1, 1, Decision::Penalties { home: 4, away: 3 }),
+            Record::Postponed(Fixture {
+                home_team: String::from("FC Rustaceans"),
+                away_team: String::from("COBOL FC 1958"),
+            }),
+        ];
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    fn must_parse_scorers() {
+        let raw = String::from("Team A 2:1 Team B (Smith 12', 45+2'; Jones 88')");
+        let parsed = MatchResult::parse_all(vec![raw.clone()]).unwrap();
+        let Some(Ok(Record::Played(result))) = parsed.first() else {
+            panic!("expected a played match");
+        };
+        let goal = |player: &str, side, minute, added| Goal {
+            player: String::from(player),
+            side,
+            minute,
+            added,
+        };
+        let expected = vec![
+            goal("Smith", Venue::Home, 12, 0),
+            goal("Smith", Venue::Home, 45, 2),
+            goal("Jones", Venue::Away, 88, 0),
+        ];
+        assert_eq!(result.goals, expected);
+        assert_eq!(format!("{result}"), raw);
+
+        let raw = vec![
+            String::from("A 2:1 B (Smith 12'; Jones 88')"),
+            String::from("A 1:0 B (12')"),
+            String::from("A 1:1 B (Smith 5'; Jones 6'; Brown 7')"),
+            String::from("A 1:1 B (Smith 5'; Jones 6' Brown 7')"),
+        ];
+        for result in MatchResult::parse_all(raw).unwrap() {
+            assert!(matches!(result, Err(ParseError::Inconsistent { .. })));
+        }
+
+        let raw = vec![
+            String::from("A 1:0 B (U21)"),
+            String::from("A 0:1 B (U21) (; Jones 88')"),
+            String::from("A 1:0 B (U21) (Smith 5';)"),
+        ];
+        for result in MatchResult::parse_all(raw.clone()).unwrap() {
+            let Ok(Record::Played(result)) = result else {
+                panic!("expected a played match");
+            };
+            assert_eq!(result.away_team, "B (U21)");
+            assert!(raw.contains(&format!("{result}")));
+        }
+    }
+
+    #[test]
+    fn must_parse_dates() {
+        let raw = String::from("2024-08-17 FC Basel 2:0 Young Boys");
+        let parsed = MatchResult::parse_all(vec![raw.clone(), String::from("2024-02-30 A 1:0 B")]);
+        let parsed = parsed.unwrap();
+        let Some(Ok(Record::Played(result))) = parsed.first() else {
+            panic!("expected a played match");
+        };
+        assert_eq!(result.date, "2024-08-17".parse().ok());
+        assert_eq!(result.home_team, "FC Basel");
+        assert_eq!(format!("{result}"), raw);
+        assert!(matches!(parsed[1], Err(ParseError::Inconsistent { .. })));
+    }
+
+    #[test]
+    fn must_reject_inconsistent_results() {
+        let raw = vec![
+            String::from("A 1:1 B w.o."),
+            String::from("A 2:1 B 4:3 pen."),
+            String::from("A 1:1 B 3:3 pen."),
+        ];
+        for result in MatchResult::parse_all(raw).unwrap() {
+            assert!(matches!(result, Err(ParseError::Inconsistent { .. })));
+        }
+
+        let raw = vec![String::from("A 2:1 B 4:3 pen.")];
+        let parsed = MatchResult::parse_knockout(raw).unwrap();
+        assert!(matches!(parsed[0], Ok(Record::Played(_))));
     }
 }
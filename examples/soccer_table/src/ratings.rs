@@ -0,0 +1,169 @@
+use crate::format::escape_csv;
+use crate::parsing::{Diagnostic, MatchResult};
+use crate::{Failure, Matchdays, Options};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::{Display, Error, Formatter};
+use std::path::Path;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EloConfig {
+    pub k_factor: f64,
+    pub home_advantage: f64,
+    pub initial: f64,
+}
+
+impl Default for EloConfig {
+    fn default() -> Self {
+        EloConfig {
+            k_factor: 20.0,
+            home_advantage: 100.0,
+            initial: 1500.0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ratings {
+    pub days: Vec<usize>,
+    pub history: Vec<(String, Vec<Option<f64>>)>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl EloConfig {
+    pub fn update(&self, ratings: &mut HashMap<String, f64>, result: &MatchResult) {
+        let home = *ratings.get(&result.home_team).unwrap_or(&self.initial);
+        let away = *ratings.get(&result.away_team).unwrap_or(&self.initial);
+        let expected = 1.0 / (1.0 + 10f64.powf((away - home - self.home_advantage) / 400.0));
+        let actual = match result.home_goals.cmp(&result.away_goals) {
+            Ordering::Greater => 1.0,
+            Ordering::Equal => 0.5,
+            Ordering::Less => 0.0,
+        };
+        let delta = self.k_factor * (actual - expected);
+        ratings.insert(result.home_team.clone(), home + delta);
+        ratings.insert(result.away_team.clone(), away - delta);
+    }
+}
+
+pub fn compute_ratings(dir: &Path, options: &Options, elo: &EloConfig) -> Result<Ratings, Failure> {
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    let mut days: Vec<usize> = Vec::new();
+    let mut snapshots: Vec<HashMap<String, f64>> = Vec::new();
+    let mut ratings: HashMap<String, f64> = HashMap::new();
+    for matchday in Matchdays::new(dir, options)? {
+        let (day, mut parsed) = matchday?;
+        diagnostics.append(&mut parsed.diagnostics);
+        for result in &parsed.results {
+            elo.update(&mut ratings, result);
+        }
+        snapshots.push(ratings.clone());
+        days.push(day);
+    }
+
+    let mut teams: Vec<(String, f64)> = ratings.into_iter().collect();
+    teams.sort_by(|(a, x), (b, y)| y.total_cmp(x).then_with(|| a.cmp(b)));
+    let history = teams
+        .into_iter()
+        .map(|(team, _)| {
+            let history = snapshots.iter().map(|s| s.get(&team).copied()).collect();
+            (team, history)
+        })
+        .collect();
+    Ok(Ratings {
+        days,
+        history,
+        diagnostics,
+    })
+}
+
+impl Ratings {
+    pub fn current(&self) -> Vec<(String, f64)> {
+        self.history
+            .iter()
+            .filter_map(|(team, history)| Some((team.clone(), (*history.last()?)?)))
+            .collect()
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("team");
+        for day in &self.days {
+            out.push_str(&format!(",{day}"));
+        }
+        out.push('\n');
+        for (team, ratings) in &self.history {
+            out.push_str(&escape_csv(team));
+            for rating in ratings {
+                out.push(',');
+                if let Some(rating) = rating {
+                    out.push_str(&format!("{rating:.1}"));
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl Display for Ratings {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let title = format!("{:>3} {:30} {:>7}", "#", "Team", "Rating");
+        f.write_fmt(format_args!("{}\n", title))?;
+        f.write_fmt(format_args!("{}\n", "-".repeat(title.chars().count())))?;
+        for (i, (team, rating)) in self.current().iter().enumerate() {
+            f.write_fmt(format_args!("{:>3} {:30} {:>7.1}\n", i + 1, team, rating))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsing::Decision;
+    use crate::test_support::temp_dir;
+    use std::fs;
+
+    #[test]
+    fn elo_update() {
+        let elo = EloConfig {
+            home_advantage: 0.0,
+            ..EloConfig::default()
+        };
+        let mut ratings: HashMap<String, f64> = HashMap::new();
+        let result = MatchResult {
+            home_team: String::from("A"),
+            away_team: String::from("B"),
+            home_goals: 1,
+            away_goals: 0,
+            decision: Decision::Regular,
+            goals: Vec::new(),
+            date: None,
+        };
+        elo.update(&mut ratings, &result);
+        assert_eq!(ratings["A"], 1510.0);
+        assert_eq!(ratings["B"], 1490.0);
+
+        let with_advantage = EloConfig::default();
+        let mut ratings: HashMap<String, f64> = HashMap::new();
+        with_advantage.update(&mut ratings, &result);
+        assert!(ratings["A"] > 1500.0 && ratings["A"] < 1510.0);
+    }
+
+    #[test]
+    fn rating_history_per_matchday() {
+        let test_dir = temp_dir("soccer-table-test-ratings");
+        let path = test_dir.as_path();
+        fs::write(path.join("01.txt"), "A 1:0 B\n").unwrap();
+        fs::write(path.join("02.txt"), "C 0:0 A\nB 1:1 \"D\", Inc.\n").unwrap();
+
+        let ratings = compute_ratings(path, &Options::default(), &EloConfig::default()).unwrap();
+        assert_eq!(ratings.days, vec![1, 2]);
+        let teams: Vec<&str> = ratings.history.iter().map(|(t, _)| t.as_str()).collect();
+        assert_eq!(teams, vec!["A", "\"D\", Inc.", "C", "B"]);
+        assert_eq!(ratings.history[1].1[0], None);
+        let csv = ratings.to_csv();
+        assert!(csv.starts_with("team,1,2\nA,"));
+        assert!(csv.contains("\n\"\"\"D\"\", Inc.\",,"));
+    }
+}
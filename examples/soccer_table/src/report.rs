@@ -0,0 +1,165 @@
+use crate::parsing::MatchResult;
+use crate::table_row::TableRow;
+use crate::Table;
+use std::fmt::{Display, Error, Formatter};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HeadToHead {
+    pub first: String,
+    pub second: String,
+    pub meetings: Vec<MatchResult>,
+    pub first_wins: u32,
+    pub second_wins: u32,
+    pub ties: u32,
+    pub first_goals: u32,
+    pub second_goals: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogEntry {
+    pub result: MatchResult,
+    pub points: i32,
+    pub total: i32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchLog {
+    pub team: String,
+    pub entries: Vec<LogEntry>,
+}
+
+impl Table {
+    pub fn head_to_head(&self, first: &str, second: &str) -> HeadToHead {
+        let first = self.canonical(first);
+        let second = self.canonical(second);
+        let mut report = HeadToHead {
+            first: first.clone(),
+            second: second.clone(),
+            meetings: Vec::new(),
+            first_wins: 0,
+            second_wins: 0,
+            ties: 0,
+            first_goals: 0,
+            second_goals: 0,
+        };
+        for result in &self.results {
+            let rows = match (result.home_team.as_str(), result.away_team.as_str()) {
+                (home, away) if home == first && away == second => self.rows_for(result),
+                (home, away) if home == second && away == first => {
+                    let (home, away) = self.rows_for(result);
+                    (away, home)
+                }
+                _ => continue,
+            };
+            report.first_wins += rows.0.wins;
+            report.second_wins += rows.1.wins;
+            report.ties += rows.0.ties;
+            report.first_goals += rows.0.goals_scored;
+            report.second_goals += rows.1.goals_scored;
+            report.meetings.push(result.clone());
+        }
+        report
+    }
+
+    pub fn match_log(&self, team: &str) -> MatchLog {
+        let team = self.canonical(team);
+        let mut total = 0;
+        let entries = self
+            .results
+            .iter()
+            .filter_map(|result| {
+                let (home, away) = self.rows_for(result);
+                let row = match team.as_str() {
+                    t if t == result.home_team => home,
+                    t if t == result.away_team => away,
+                    _ => return None,
+                };
+                total += row.points;
+                Some(LogEntry {
+                    result: result.clone(),
+                    points: row.points,
+                    total,
+                })
+            })
+            .collect();
+        MatchLog { team, entries }
+    }
+
+    fn rows_for(&self, result: &MatchResult) -> (TableRow, TableRow) {
+        TableRow::from_with_points(result.clone(), &self.rules.points)
+    }
+}
+
+impl Display for HeadToHead {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        f.write_fmt(format_args!("{} vs. {}\n", self.first, self.second))?;
+        for meeting in &self.meetings {
+            f.write_fmt(format_args!("  {meeting}\n"))?;
+        }
+        f.write_fmt(format_args!(
+            "{} meetings: {} wins {}, {} wins {}, {} ties, goals {}:{}\n",
+            self.meetings.len(),
+            self.first,
+            self.first_wins,
+            self.second,
+            self.second_wins,
+            self.ties,
+            self.first_goals,
+            self.second_goals
+        ))
+    }
+}
+
+impl Display for MatchLog {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let title = format!("{:>3} {:60} {:>4} {:>5}", "#", "Result", "P", "Total");
+        f.write_fmt(format_args!("{}\n", title))?;
+        f.write_fmt(format_args!("{}\n", "-".repeat(title.chars().count())))?;
+        for (i, e) in self.entries.iter().enumerate() {
+            f.write_fmt(format_args!(
+                "{:>3} {:60} {:>4} {:>5}\n",
+                i + 1,
+                e.result.to_string(),
+                e.points,
+                e.total
+            ))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::result;
+    use crate::{build_table, RankingRules};
+
+    fn table() -> Table {
+        let results = vec![
+            result("A", 2, 1, "B"),
+            result("C", 0, 0, "A"),
+            result("B", 3, 3, "A"),
+            result("B", 1, 0, "C"),
+            result("A", 0, 2, "B"),
+        ];
+        build_table(results, &RankingRules::default()).unwrap()
+    }
+
+    #[test]
+    fn head_to_head_record() {
+        let report = table().head_to_head("B", "A");
+        assert_eq!(report.meetings.len(), 3);
+        assert_eq!(
+            (report.first_wins, report.second_wins, report.ties),
+            (1, 1, 1)
+        );
+        assert_eq!((report.first_goals, report.second_goals), (6, 5));
+    }
+
+    #[test]
+    fn match_log_running_points() {
+        let log = table().match_log("A");
+        let points: Vec<(i32, i32)> = log.entries.iter().map(|e| (e.points, e.total)).collect();
+        assert_eq!(points, vec![(3, 3), (1, 4), (1, 5), (0, 5)]);
+    }
+}
@@ -0,0 +1,413 @@
+use crate::parsing::{Decision, MatchResult};
+use crate::table_row::{RowError, TableRow};
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TieBreaker {
+    HeadToHead,
+    GoalsDiff,
+    GoalsScored,
+    AwayGoals,
+    Wins,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointsScheme {
+    pub win: i32,
+    pub tie: i32,
+    pub defeat: i32,
+    pub extra_time_win: i32,
+    pub extra_time_defeat: i32,
+    pub penalty_win: i32,
+    pub penalty_defeat: i32,
+    pub forfeit_win: i32,
+    pub forfeit_defeat: i32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RankingRules {
+    pub points: PointsScheme,
+    pub tie_breakers: Vec<TieBreaker>,
+}
+
+#[derive(Debug)]
+pub enum RulesError {
+    Reading {
+        path: String,
+        err: String,
+    },
+    Syntax {
+        line: usize,
+        val: String,
+    },
+    UnknownKey {
+        line: usize,
+        key: String,
+    },
+    InvalidValue {
+        line: usize,
+        key: String,
+        val: String,
+    },
+}
+
+impl Error for RulesError {}
+
+impl Display for RulesError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            RulesError::Reading { path: p, err: e } => write!(f, "read rules {p}: {e}"),
+            RulesError::Syntax { line: l, val: v } => {
+                write!(f, "line {l}: expected 'key = value', got '{v}'")
+            }
+            RulesError::UnknownKey { line: l, key: k } => write!(f, "line {l}: unknown key {k}"),
+            RulesError::InvalidValue {
+                line: l,
+                key: k,
+                val: v,
+            } => write!(f, "line {l}: invalid value '{v}' for {k}"),
+        }
+    }
+}
+
+impl Default for PointsScheme {
+    fn default() -> Self {
+        PointsScheme {
+            win: 3,
+            tie: 1,
+            defeat: 0,
+            extra_time_win: 3,
+            extra_time_defeat: 0,
+            penalty_win: 1,
+            penalty_defeat: 1,
+            forfeit_win: 3,
+            forfeit_defeat: 0,
+        }
+    }
+}
+
+impl PointsScheme {
+    pub fn max_per_match(&self) -> i32 {
+        [
+            self.win,
+            self.tie,
+            self.extra_time_win,
+            self.penalty_win,
+            self.forfeit_win,
+        ]
+        .into_iter()
+        .max()
+        .unwrap_or(0)
+    }
+
+    pub fn min_per_match(&self) -> i32 {
+        [
+            self.defeat,
+            self.tie,
+            self.extra_time_defeat,
+            self.penalty_defeat,
+            self.forfeit_defeat,
+        ]
+        .into_iter()
+        .min()
+        .unwrap_or(0)
+    }
+
+    pub fn for_decision(&self, decision: &Decision) -> (i32, i32) {
+        match decision {
+            Decision::Regular => (self.win, self.defeat),
+            Decision::ExtraTime => (self.extra_time_win, self.extra_time_defeat),
+            Decision::Penalties { .. } => (self.penalty_win, self.penalty_defeat),
+            Decision::Forfeit => (self.forfeit_win, self.forfeit_defeat),
+        }
+    }
+}
+
+impl Default for RankingRules {
+    fn default() -> Self {
+        RankingRules {
+            points: PointsScheme::default(),
+            tie_breakers: vec![TieBreaker::GoalsDiff, TieBreaker::Wins],
+        }
+    }
+}
+
+impl TieBreaker {
+    fn parse(s: &str) -> Option<TieBreaker> {
+        match s {
+            "head_to_head" => Some(TieBreaker::HeadToHead),
+            "goals_diff" => Some(TieBreaker::GoalsDiff),
+            "goals_scored" => Some(TieBreaker::GoalsScored),
+            "away_goals" => Some(TieBreaker::AwayGoals),
+            "wins" => Some(TieBreaker::Wins),
+            _ => None,
+        }
+    }
+}
+
+impl RankingRules {
+    pub fn load(path: &Path) -> Result<RankingRules, RulesError> {
+        let content = fs::read_to_string(path).map_err(|e| RulesError::Reading {
+            path: path.display().to_string(),
+            err: e.to_string(),
+        })?;
+        Self::parse(&content)
+    }
+
+    pub fn parse(content: &str) -> Result<RankingRules, RulesError> {
+        let mut rules = RankingRules::default();
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, val) = line
+                .split_once('=')
+                .map(|(k, v)| (k.trim(), v.trim()))
+                .ok_or(RulesError::Syntax {
+                    line: i + 1,
+                    val: line.into(),
+                })?;
+            let invalid = || RulesError::InvalidValue {
+                line: i + 1,
+                key: key.into(),
+                val: val.into(),
+            };
+            match key {
+                "win" => rules.points.win = val.parse().map_err(|_| invalid())?,
+                "tie" => rules.points.tie = val.parse().map_err(|_| invalid())?,
+                "defeat" => rules.points.defeat = val.parse().map_err(|_| invalid())?,
+                "extra_time_win" => {
+                    rules.points.extra_time_win = val.parse().map_err(|_| invalid())?
+                }
+                "extra_time_defeat" => {
+                    rules.points.extra_time_defeat = val.parse().map_err(|_| invalid())?
+                }
+                "penalty_win" => rules.points.penalty_win = val.parse().map_err(|_| invalid())?,
+                "penalty_defeat" => {
+                    rules.points.penalty_defeat = val.parse().map_err(|_| invalid())?
+                }
+                "forfeit_win" => rules.points.forfeit_win = val.parse().map_err(|_| invalid())?,
+                "forfeit_defeat" => {
+                    rules.points.forfeit_defeat = val.parse().map_err(|_| invalid())?
+                }
+                "tie_breakers" => {
+                    rules.tie_breakers = val
+                        .split(',')
+                        .map(|s| s.trim())
+                        .filter(|s| !s.is_empty())
+                        .map(|s| TieBreaker::parse(s).ok_or_else(invalid))
+                        .collect::<Result<Vec<_>, _>>()?
+                }
+                _ => {
+                    return Err(RulesError::UnknownKey {
+                        line: i + 1,
+                        key: key.into(),
+                    })
+                }
+            }
+        }
+        Ok(rules)
+    }
+
+    pub fn rank(
+        &self,
+        rows: &[TableRow],
+        results: &[MatchResult],
+    ) -> Result<Vec<TableRow>, RowError> {
+        let mut rows = rows.to_vec();
+        rows.sort_by(|a, b| b.points.cmp(&a.points).then(a.team.cmp(&b.team)));
+        let mut ranked: Vec<TableRow> = Vec::new();
+        for tied in rows.chunk_by(|a, b| a.points == b.points) {
+            ranked.append(&mut self.break_ties(tied.to_vec(), &self.tie_breakers, results)?);
+        }
+        Ok(ranked)
+    }
+
+    fn break_ties(
+        &self,
+        rows: Vec<TableRow>,
+        criteria: &[TieBreaker],
+        results: &[MatchResult],
+    ) -> Result<Vec<TableRow>, RowError> {
+        let Some((criterion, rest)) = criteria.split_first() else {
+            return Ok(rows);
+        };
+        if rows.len() < 2 {
+            return Ok(rows);
+        }
+        let keys = self.keys(*criterion, &rows, results)?;
+        let mut keyed: Vec<(Vec<i64>, TableRow)> = rows
+            .iter()
+            .map(|r| (keys.get(&r.team).cloned().unwrap_or_default(), r.clone()))
+            .collect();
+        keyed.sort_by(|(a, _), (b, _)| b.cmp(a));
+        let mut ranked: Vec<TableRow> = Vec::new();
+        for group in keyed.chunk_by(|(a, _), (b, _)| a == b) {
+            let group: Vec<TableRow> = group.iter().map(|(_, r)| r.clone()).collect();
+            if *criterion == TieBreaker::HeadToHead && group.len() < rows.len() {
+                // the mini-table is re-applied to the teams still tied among themselves
+                ranked.append(&mut self.break_ties(group, criteria, results)?);
+            } else {
+                ranked.append(&mut self.break_ties(group, rest, results)?);
+            }
+        }
+        Ok(ranked)
+    }
+
+    fn keys(
+        &self,
+        criterion: TieBreaker,
+        rows: &[TableRow],
+        results: &[MatchResult],
+    ) -> Result<HashMap<String, Vec<i64>>, RowError> {
+        Ok(match criterion {
+            TieBreaker::HeadToHead => self
+                .head_to_head(rows, results)?
+                .into_iter()
+                .map(|(team, r)| {
+                    let key = vec![r.points as i64, r.goals_diff as i64, r.goals_scored as i64];
+                    (team, key)
+                })
+                .collect(),
+            TieBreaker::GoalsDiff => Self::key_by(rows, |r| r.goals_diff as i64),
+            TieBreaker::GoalsScored => Self::key_by(rows, |r| r.goals_scored as i64),
+            TieBreaker::Wins => Self::key_by(rows, |r| r.wins as i64),
+            TieBreaker::AwayGoals => Self::key_by(rows, |r| {
+                results
+                    .iter()
+                    .filter(|m| m.away_team == r.team)
+                    .map(|m| m.away_goals as i64)
+                    .sum()
+            }),
+        })
+    }
+
+    fn key_by(rows: &[TableRow], f: impl Fn(&TableRow) -> i64) -> HashMap<String, Vec<i64>> {
+        rows.iter().map(|r| (r.team.clone(), vec![f(r)])).collect()
+    }
+
+    fn head_to_head(
+        &self,
+        rows: &[TableRow],
+        results: &[MatchResult],
+    ) -> Result<HashMap<String, TableRow>, RowError> {
+        let teams: HashSet<&str> = rows.iter().map(|r| r.team.as_str()).collect();
+        let mut mini: HashMap<String, TableRow> = rows
+            .iter()
+            .map(|r| (r.team.clone(), TableRow::new(&r.team)))
+            .collect();
+        let direct = results.iter().filter(|m| {
+            teams.contains(m.home_team.as_str()) && teams.contains(m.away_team.as_str())
+        });
+        for result in direct {
+            let (home, away) = TableRow::from_with_points(result.clone(), &self.points);
+            for row in [home, away] {
+                if let Some(acc) = mini.remove(&row.team) {
+                    let combined = acc.combine(row)?;
+                    mini.insert(combined.team.clone(), combined);
+                }
+            }
+        }
+        Ok(mini)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::result;
+
+    fn row(team: &str, points: i32, goals_diff: i32, wins: u32) -> TableRow {
+        let mut row = TableRow::new(team);
+        row.points = points;
+        row.goals_diff = goals_diff;
+        row.wins = wins;
+        row
+    }
+
+    fn teams(rows: &[TableRow]) -> Vec<&str> {
+        rows.iter().map(|r| r.team.as_str()).collect()
+    }
+
+    #[test]
+    fn parse_rules() {
+        let content = "
+            # two points for a win
+            win = 2
+            penalty_win = 2
+            tie_breakers = head_to_head, goals_scored, away_goals
+        ";
+        let expected = RankingRules {
+            points: PointsScheme {
+                win: 2,
+                penalty_win: 2,
+                ..PointsScheme::default()
+            },
+            tie_breakers: vec![
+                TieBreaker::HeadToHead,
+                TieBreaker::GoalsScored,
+                TieBreaker::AwayGoals,
+            ],
+        };
+        assert_eq!(RankingRules::parse(content).unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_rules_rejects_unknown_input() {
+        assert!(RankingRules::parse("loss = 0").is_err());
+        assert!(RankingRules::parse("win: 3").is_err());
+        assert!(RankingRules::parse("win = many").is_err());
+        assert!(RankingRules::parse("tie_breakers = coin_toss").is_err());
+    }
+
+    #[test]
+    fn default_rules_rank_like_ord() {
+        let rows = vec![
+            row("D", 40, 10, 7),
+            row("C", 40, 10, 8),
+            row("E", 40, 10, 7),
+            row("B", 40, 20, 0),
+            row("A", 50, 0, 0),
+        ];
+        let mut sorted = rows.clone();
+        sorted.sort();
+        let ranked = RankingRules::default().rank(&rows, &[]).unwrap();
+        assert_eq!(ranked, sorted);
+    }
+
+    #[test]
+    fn rank_by_head_to_head() {
+        let results = vec![
+            result("A", 0, 1, "B"),
+            result("B", 0, 0, "C"),
+            result("C", 2, 0, "A"),
+        ];
+        let rows = vec![row("A", 10, 9, 3), row("B", 10, 0, 3), row("C", 10, 0, 3)];
+        let rules = RankingRules {
+            points: PointsScheme::default(),
+            tie_breakers: vec![TieBreaker::HeadToHead, TieBreaker::GoalsDiff],
+        };
+        // B and C have 4 points each in the mini-table, C has the better goal difference
+        assert_eq!(
+            teams(&rules.rank(&rows, &results).unwrap()),
+            vec!["C", "B", "A"]
+        );
+    }
+
+    #[test]
+    fn rank_by_away_goals() {
+        let results = vec![result("A", 1, 2, "B"), result("B", 1, 1, "A")];
+        let rows = vec![row("A", 1, -1, 0), row("B", 1, -1, 0)];
+        let rules = RankingRules {
+            points: PointsScheme::default(),
+            tie_breakers: vec![TieBreaker::AwayGoals],
+        };
+        assert_eq!(teams(&rules.rank(&rows, &results).unwrap()), vec!["B", "A"]);
+    }
+}
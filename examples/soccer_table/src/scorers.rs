@@ -0,0 +1,126 @@
+use crate::{Table, Venue};
+use std::collections::HashMap;
+use std::fmt::{Display, Error, Formatter};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scorer {
+    pub rank: usize,
+    pub player: String,
+    pub team: String,
+    pub goals: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scorers {
+    pub rows: Vec<Scorer>,
+}
+
+impl Table {
+    pub fn scorers(&self) -> Scorers {
+        let mut goals: HashMap<(&str, &str), u32> = HashMap::new();
+        for result in &self.results {
+            for goal in &result.goals {
+                let team = match goal.side {
+                    Venue::Home => &result.home_team,
+                    Venue::Away => &result.away_team,
+                };
+                *goals.entry((&goal.player, team)).or_default() += 1;
+            }
+        }
+        let mut rows: Vec<Scorer> = goals
+            .into_iter()
+            .map(|((player, team), goals)| Scorer {
+                rank: 0,
+                player: player.to_string(),
+                team: team.to_string(),
+                goals,
+            })
+            .collect();
+        rows.sort_by(|a, b| {
+            b.goals
+                .cmp(&a.goals)
+                .then_with(|| a.player.cmp(&b.player))
+                .then_with(|| a.team.cmp(&b.team))
+        });
+        Scorers { rows: ranked(rows) }
+    }
+
+    pub fn team_scorers(&self, team: &str) -> Scorers {
+        let team = self.canonical(team);
+        let rows = self
+            .scorers()
+            .rows
+            .into_iter()
+            .filter(|s| s.team == team)
+            .collect();
+        Scorers { rows: ranked(rows) }
+    }
+}
+
+fn ranked(mut rows: Vec<Scorer>) -> Vec<Scorer> {
+    // players with the same number of goals share a rank
+    for i in 0..rows.len() {
+        rows[i].rank = match i {
+            0 => 1,
+            _ if rows[i].goals == rows[i - 1].goals => rows[i - 1].rank,
+            _ => i + 1,
+        };
+    }
+    rows
+}
+
+impl Display for Scorers {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let title = format!("{:>3} {:30} {:30} {:>4}", "#", "Player", "Team", "G");
+        f.write_fmt(format_args!("{}\n", title))?;
+        f.write_fmt(format_args!("{}\n", "-".repeat(title.chars().count())))?;
+        for s in &self.rows {
+            f.write_fmt(format_args!(
+                "{:>3} {:30} {:30} {:>4}\n",
+                s.rank, s.player, s.team, s.goals
+            ))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::temp_dir;
+    use crate::{compute_table, Options};
+
+    #[test]
+    fn top_and_team_scorers() {
+        let test_dir = temp_dir("soccer-table-test-scorers");
+        let path = test_dir.as_path();
+        std::fs::write(
+            path.join("01.txt"),
+            "A 2:1 B (Smith 12', 40'; Jones 88')\nC 1:0 D (Miller 90+3';)\n",
+        )
+        .unwrap();
+        std::fs::write(
+            path.join("02.txt"),
+            "B 1:1 C (Jones 3'; Miller 9')\nD 0:0 A\n",
+        )
+        .unwrap();
+
+        let table = compute_table(path, None, &Options::default()).unwrap();
+        let scorers = table.scorers();
+        let scorers: Vec<(usize, &str, &str, u32)> = scorers
+            .rows
+            .iter()
+            .map(|s| (s.rank, s.player.as_str(), s.team.as_str(), s.goals))
+            .collect();
+        assert_eq!(
+            scorers,
+            vec![
+                (1, "Jones", "B", 2),
+                (1, "Miller", "C", 2),
+                (1, "Smith", "A", 2)
+            ]
+        );
+        let team = table.team_scorers("C");
+        assert_eq!(team.rows.len(), 1);
+        assert_eq!(team.rows[0].player, "Miller");
+    }
+}
@@ -0,0 +1,214 @@
+use crate::parsing::read_lines;
+use crate::table_row::TableRow;
+use crate::{compute_table, Failure, Options, RankingRules, Table};
+use std::fmt::{Display, Error, Formatter};
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Group {
+    pub name: String,
+    pub dir: PathBuf,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Competition {
+    pub name: String,
+    pub groups: Vec<Group>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Season {
+    pub competitions: Vec<Competition>,
+}
+
+pub struct CompetitionTables {
+    pub name: String,
+    pub groups: Vec<(String, Table)>,
+}
+
+pub struct BestPlaced {
+    pub position: usize,
+    pub rows: Vec<(String, TableRow)>,
+}
+
+impl Season {
+    pub fn load(path: &Path) -> Result<Season, Failure> {
+        let lines =
+            read_lines(path).map_err(|e| Failure::Io(format!("read {}: {e}", path.display())))?;
+        let base = path.parent().unwrap_or(Path::new("."));
+        let mut season = Season::default();
+        for (number, line) in lines {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                season.competitions.push(Competition {
+                    name: name.trim().to_string(),
+                    groups: Vec::new(),
+                });
+                continue;
+            }
+            let error = |reason: &str| {
+                Failure::Parsing(format!(
+                    "{}:{number}: {reason}, got '{line}'",
+                    path.display()
+                ))
+            };
+            let Some((group, dir)) = line.split_once('=') else {
+                return Err(error("expected '[Competition]' or 'Group = DIR'"));
+            };
+            let Some(competition) = season.competitions.last_mut() else {
+                return Err(error("group outside of a competition"));
+            };
+            competition.groups.push(Group {
+                name: group.trim().to_string(),
+                dir: base.join(dir.trim()),
+            });
+        }
+        Ok(season)
+    }
+}
+
+pub fn compute_season(
+    season: &Season,
+    day: Option<usize>,
+    options: &Options,
+) -> Result<Vec<CompetitionTables>, Failure> {
+    season
+        .competitions
+        .iter()
+        .map(|c| {
+            let groups = c
+                .groups
+                .iter()
+                .map(|g| Ok((g.name.clone(), compute_table(&g.dir, day, options)?)))
+                .collect::<Result<Vec<(String, Table)>, Failure>>()?;
+            Ok(CompetitionTables {
+                name: c.name.clone(),
+                groups,
+            })
+        })
+        .collect()
+}
+
+impl CompetitionTables {
+    pub fn best_placed(&self, position: usize) -> Result<BestPlaced, Failure> {
+        let mut placed: Vec<(String, TableRow)> = Vec::new();
+        for (group, table) in &self.groups {
+            if let Some(row) = table.ranked()?.into_iter().find(|r| r.rank == position) {
+                placed.push((group.clone(), row));
+            }
+        }
+        let rows: Vec<TableRow> = placed.iter().map(|(_, r)| r.clone()).collect();
+        let rules = match self.groups.first() {
+            Some((_, table)) => table.rules.clone(),
+            None => RankingRules::default(),
+        };
+        let rows = rules
+            .rank(&rows, &[])?
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, row)| {
+                let (group, _) = placed.iter().find(|(_, r)| r.team == row.team)?;
+                Some((group.clone(), TableRow { rank: i + 1, ..row }))
+            })
+            .collect();
+        Ok(BestPlaced { position, rows })
+    }
+}
+
+impl Display for BestPlaced {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let title = format!(
+            "{:>3} {:10} {:30} {:>4} {:>4} {:>4} {:>4} {:>4} {:>4} {:>4}",
+            "#", "Group", "Team", "P", "W", "T", "L", "+", "-", "="
+        );
+        f.write_fmt(format_args!("{}\n", title))?;
+        f.write_fmt(format_args!("{}\n", "-".repeat(title.chars().count())))?;
+        for (group, r) in &self.rows {
+            f.write_fmt(format_args!(
+                "{:>3} {:10} {:30} {:>4} {:>4} {:>4} {:>4} {:>4} {:>4} {:>4}\n",
+                r.rank,
+                group,
+                r.team,
+                r.points,
+                r.wins,
+                r.ties,
+                r.defeats,
+                r.goals_scored,
+                r.goals_conceded,
+                r.goals_diff
+            ))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+    use std::fs;
+
+    #[test]
+    fn load_manifest() {
+        let test_dir = temp_dir("soccer-table-test-season");
+        let path = test_dir.as_path();
+        let manifest = path.join("season.txt");
+        fs::write(
+            &manifest,
+            "# season 2024\n[Super League]\nLeague = super\n\n[Euro 2024]\nA = euro/a\nB = euro/b\n",
+        )
+        .unwrap();
+        let season = Season::load(&manifest).unwrap();
+        assert_eq!(season.competitions.len(), 2);
+        assert_eq!(season.competitions[0].name, "Super League");
+        assert_eq!(
+            season.competitions[1].groups[1],
+            Group {
+                name: String::from("B"),
+                dir: path.join("euro/b"),
+            }
+        );
+
+        fs::write(&manifest, "A = euro/a\n").unwrap();
+        assert!(matches!(Season::load(&manifest), Err(Failure::Parsing(_))));
+    }
+
+    #[test]
+    fn best_third_placed() {
+        let test_dir = temp_dir("soccer-table-test-groups");
+        let path = test_dir.as_path();
+        for (group, results) in [
+            ("a", "A1 2:0 A2\nA2 1:0 A3\nA1 3:0 A3\n"),
+            ("b", "B1 1:0 B2\nB2 0:0 B3\nB3 0:2 B1\n"),
+        ] {
+            fs::create_dir_all(path.join(group)).unwrap();
+            fs::write(path.join(group).join("01.txt"), results).unwrap();
+        }
+        let season = Season {
+            competitions: vec![Competition {
+                name: String::from("Cup"),
+                groups: vec![
+                    Group {
+                        name: String::from("A"),
+                        dir: path.join("a"),
+                    },
+                    Group {
+                        name: String::from("B"),
+                        dir: path.join("b"),
+                    },
+                ],
+            }],
+        };
+        let tables = compute_season(&season, None, &Options::default()).unwrap();
+        assert_eq!(tables[0].groups.len(), 2);
+        let thirds = tables[0].best_placed(3).unwrap();
+        let teams: Vec<(&str, &str, usize)> = thirds
+            .rows
+            .iter()
+            .map(|(g, r)| (g.as_str(), r.team.as_str(), r.rank))
+            .collect();
+        assert_eq!(teams, vec![("B", "B3", 1), ("A", "A3", 2)]);
+    }
+}
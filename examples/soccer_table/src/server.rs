@@ -0,0 +1,238 @@
+use crate::format::{escape_json, row_json};
+use crate::{compute_table, Failure, Options, TableFormat};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::Path;
+use std::time::Duration;
+
+// one request at a time, so a silent client must not hold up everyone else
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    fn json(body: String) -> Response {
+        Response { status: 200, body }
+    }
+
+    fn error(status: u16, message: &str) -> Response {
+        Response {
+            status,
+            body: format!("{{\"error\":\"{}\"}}\n", escape_json(message)),
+        }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            _ => "Internal Server Error",
+        }
+    }
+}
+
+pub fn serve(port: u16, dir: &Path, options: &Options) -> io::Result<()> {
+    let listener = TcpListener::bind(("127.0.0.1", port))?;
+    for stream in listener.incoming() {
+        match stream {
+            Ok(mut stream) => {
+                let timeouts = stream
+                    .set_read_timeout(Some(TIMEOUT))
+                    .and_then(|_| stream.set_write_timeout(Some(TIMEOUT)));
+                if let Err(e) = timeouts.and_then(|_| handle(&mut stream, dir, options)) {
+                    eprintln!("handle request: {e}");
+                }
+            }
+            Err(e) => eprintln!("accept connection: {e}"),
+        }
+    }
+    Ok(())
+}
+
+pub fn handle<S: Read + Write>(stream: &mut S, dir: &Path, options: &Options) -> io::Result<()> {
+    let mut reader = BufReader::new(&mut *stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+    }
+    let mut parts = request_line.split_whitespace();
+    let response = match (parts.next(), parts.next()) {
+        (Some("GET"), Some(target)) => route(target, dir, options),
+        (Some(_), Some(_)) => Response::error(405, "only GET is supported"),
+        _ => Response::error(400, "malformed request line"),
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.reason(),
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+pub fn route(target: &str, dir: &Path, options: &Options) -> Response {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let mut day: Option<usize> = None;
+    for (key, value) in query.split('&').filter_map(|p| p.split_once('=')) {
+        match (key, value.parse::<usize>()) {
+            ("day", Ok(d)) => day = Some(d),
+            ("day", Err(e)) => return Response::error(400, &format!("parse day '{value}': {e}")),
+            _ => {}
+        }
+    }
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let result = match segments.as_slice() {
+        ["table"] => standings(dir, day, options),
+        ["team", name] => match percent_decode(name) {
+            Some(name) => team(&name, dir, day, options),
+            None => return Response::error(400, "invalid percent-encoding in team name"),
+        },
+        _ => return Response::error(404, &format!("no such resource '{path}'")),
+    };
+    match result {
+        Ok(response) => response,
+        Err(e) => Response::error(500, &format!("{e}")),
+    }
+}
+
+fn standings(dir: &Path, day: Option<usize>, options: &Options) -> Result<Response, Failure> {
+    let table = compute_table(dir, day, options)?;
+    Ok(Response::json(TableFormat::Json.render(&table)?))
+}
+
+fn team(
+    name: &str,
+    dir: &Path,
+    day: Option<usize>,
+    options: &Options,
+) -> Result<Response, Failure> {
+    let table = compute_table(dir, day, options)?;
+    let name = table.canonical(name);
+    let Some(row) = table.ranked()?.into_iter().find(|r| r.team == name) else {
+        return Ok(Response::error(404, &format!("no such team '{name}'")));
+    };
+    let results: Vec<String> = table
+        .results
+        .iter()
+        .filter(|r| r.home_team == name || r.away_team == name)
+        .map(|r| format!("\"{}\"", escape_json(&r.to_string())))
+        .collect();
+    Ok(Response::json(format!(
+        "{{\"standing\":{},\"results\":[{}]}}\n",
+        row_json(&table, &row),
+        results.join(",")
+    )))
+}
+
+fn percent_decode(s: &str) -> Option<String> {
+    let bytes = s.as_bytes();
+    let mut decoded: Vec<u8> = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let hex = std::str::from_utf8(bytes.get(i + 1..i + 3)?).ok()?;
+                decoded.push(u8::from_str_radix(hex, 16).ok()?);
+                i += 3;
+            }
+            b => {
+                decoded.push(b);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+    use std::fs;
+    use std::io::Cursor;
+    use std::path::PathBuf;
+
+    struct Connection {
+        request: Cursor<Vec<u8>>,
+        response: Vec<u8>,
+    }
+
+    impl Read for Connection {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.request.read(buf)
+        }
+    }
+
+    impl Write for Connection {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.response.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn results_dir(name: &str) -> PathBuf {
+        let test_dir = temp_dir(name);
+        fs::write(test_dir.join("01.txt"), "FC Basel 2:0 Young Boys\n").unwrap();
+        fs::write(test_dir.join("02.txt"), "Young Boys 3:0 FC Basel\n").unwrap();
+        test_dir
+    }
+
+    #[test]
+    fn route_requests() {
+        let dir = results_dir("soccer-table-test-server-routes");
+        let options = Options::default();
+
+        let table = route("/table", &dir, &options);
+        assert_eq!(table.status, 200);
+        assert!(table
+            .body
+            .starts_with("[{\"rank\":1,\"team\":\"Young Boys\""));
+
+        let first_day = route("/table?day=1", &dir, &options);
+        assert!(first_day
+            .body
+            .starts_with("[{\"rank\":1,\"team\":\"FC Basel\""));
+
+        let team = route("/team/FC%20Basel?day=2", &dir, &options);
+        assert_eq!(team.status, 200);
+        assert!(team
+            .body
+            .starts_with("{\"standing\":{\"rank\":2,\"team\":\"FC Basel\""));
+        assert!(team
+            .body
+            .contains("\"results\":[\"FC Basel 2:0 Young Boys\","));
+
+        assert_eq!(route("/team/Nobody", &dir, &options).status, 404);
+        assert_eq!(route("/table?day=x", &dir, &options).status, 400);
+        assert_eq!(route("/elsewhere", &dir, &options).status, 404);
+    }
+
+    #[test]
+    fn handle_http_exchange() {
+        let dir = results_dir("soccer-table-test-server-http");
+        let mut connection = Connection {
+            request: Cursor::new(b"GET /table?day=1 HTTP/1.1\r\nHost: localhost\r\n\r\n".to_vec()),
+            response: Vec::new(),
+        };
+        handle(&mut connection, &dir, &Options::default()).unwrap();
+        let response = String::from_utf8(connection.response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Content-Type: application/json\r\n"));
+        assert!(response.ends_with("}]\n"));
+    }
+}
@@ -0,0 +1,223 @@
+use crate::adjustments::apply_adjustments;
+use crate::parsing::{Decision, Fixture, MatchResult};
+use crate::{build_table, Failure, Table};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+use std::fmt::{Display, Error, Formatter};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Simulation {
+    pub runs: usize,
+    pub seed: u64,
+    pub probabilities: Vec<(String, Vec<f64>)>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Strength {
+    attack: f64,
+    defence: f64,
+}
+
+impl Display for Simulation {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let positions = self.probabilities.len();
+        let mut title = format!("{:30}", "Team");
+        for p in 1..=positions {
+            title.push_str(&format!(" {p:>5}"));
+        }
+        f.write_fmt(format_args!("{}\n", title))?;
+        f.write_fmt(format_args!("{}\n", "-".repeat(title.chars().count())))?;
+        for (team, probabilities) in &self.probabilities {
+            f.write_fmt(format_args!("{team:30}"))?;
+            for p in probabilities {
+                f.write_fmt(format_args!(" {:>5.1}", p * 100.0))?;
+            }
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
+
+impl Table {
+    pub fn simulate(
+        &self,
+        schedule: &[Fixture],
+        runs: usize,
+        seed: u64,
+    ) -> Result<Simulation, Failure> {
+        let open = self.open_fixtures(schedule);
+        let (home_avg, away_avg) = self.average_goals();
+        let strengths = self.strengths(home_avg, away_avg);
+        let average = Strength {
+            attack: 1.0,
+            defence: 1.0,
+        };
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut teams: Vec<String> = self.ranked()?.into_iter().map(|r| r.team).collect();
+        // teams yet to play their first match only show up in the open fixtures
+        for fixture in &open {
+            for team in [&fixture.home_team, &fixture.away_team] {
+                if !teams.contains(team) {
+                    teams.push(team.clone());
+                }
+            }
+        }
+        let mut counts: HashMap<String, Vec<usize>> = teams
+            .iter()
+            .map(|t| (t.clone(), vec![0; teams.len()]))
+            .collect();
+        for _ in 0..runs {
+            let mut results = self.results.clone();
+            for fixture in &open {
+                let home = strengths.get(&fixture.home_team).unwrap_or(&average);
+                let away = strengths.get(&fixture.away_team).unwrap_or(&average);
+                results.push(MatchResult {
+                    home_team: fixture.home_team.clone(),
+                    away_team: fixture.away_team.clone(),
+                    home_goals: poisson(&mut rng, home.attack * away.defence * home_avg),
+                    away_goals: poisson(&mut rng, away.attack * home.defence * away_avg),
+                    decision: Decision::Regular,
+                    goals: Vec::new(),
+                    date: None,
+                });
+            }
+            let mut table = build_table(results, &self.rules)?;
+            // the table's adjustments are already resolved to canonical names and due matchdays
+            apply_adjustments(&mut table.rows, &self.adjustments, &self.names, usize::MAX)?;
+            for row in table.ranked()? {
+                if let Some(positions) = counts.get_mut(&row.team) {
+                    if let Some(count) = positions.get_mut(row.rank - 1) {
+                        *count += 1;
+                    }
+                }
+            }
+        }
+        let probabilities = teams
+            .into_iter()
+            .map(|team| {
+                let positions = counts.remove(&team).unwrap_or_default();
+                let probabilities = positions
+                    .iter()
+                    .map(|c| *c as f64 / runs.max(1) as f64)
+                    .collect();
+                (team, probabilities)
+            })
+            .collect();
+        Ok(Simulation {
+            runs,
+            seed,
+            probabilities,
+        })
+    }
+
+    fn average_goals(&self) -> (f64, f64) {
+        if self.results.is_empty() {
+            return (1.5, 1.2);
+        }
+        let games = self.results.len() as f64;
+        let home: u32 = self.results.iter().map(|r| r.home_goals as u32).sum();
+        let away: u32 = self.results.iter().map(|r| r.away_goals as u32).sum();
+        (home as f64 / games, away as f64 / games)
+    }
+
+    fn strengths(&self, home_avg: f64, away_avg: f64) -> HashMap<String, Strength> {
+        let per_team = (home_avg + away_avg) / 2.0;
+        self.rows
+            .iter()
+            .map(|r| {
+                // one league-average game is added so that teams without goals keep a chance
+                let games = (r.wins + r.ties + r.defeats) as f64 + 1.0;
+                let scored = (r.goals_scored as f64 + per_team) / games;
+                let conceded = (r.goals_conceded as f64 + per_team) / games;
+                let strength = Strength {
+                    attack: scored / per_team.max(f64::EPSILON),
+                    defence: conceded / per_team.max(f64::EPSILON),
+                };
+                (r.team.clone(), strength)
+            })
+            .collect()
+    }
+}
+
+fn poisson(rng: &mut StdRng, lambda: f64) -> u16 {
+    let limit = (-lambda).exp();
+    let mut goals = 0;
+    let mut p: f64 = rng.gen();
+    while p > limit && goals < u16::MAX {
+        goals += 1;
+        p *= rng.gen::<f64>();
+    }
+    goals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{fixture, result};
+    use crate::{Adjustment, Aliases, RankingRules};
+
+    fn table() -> Table {
+        let results = vec![
+            result("A", 3, 0, "B"),
+            result("C", 1, 1, "D"),
+            result("B", 0, 2, "C"),
+            result("D", 1, 4, "A"),
+        ];
+        build_table(results, &RankingRules::default()).unwrap()
+    }
+
+    #[test]
+    fn simulation_is_reproducible() {
+        let schedule = vec![fixture("A", "C"), fixture("B", "D"), fixture("C", "B")];
+        let first = table().simulate(&schedule, 200, 42).unwrap();
+        let second = table().simulate(&schedule, 200, 42).unwrap();
+        assert_eq!(first, second);
+        for (_, probabilities) in &first.probabilities {
+            let total: f64 = probabilities.iter().sum();
+            assert!((total - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn simulation_includes_teams_without_results() {
+        let schedule = vec![fixture("E", "A"), fixture("B", "E")];
+        let simulation = table().simulate(&schedule, 50, 3).unwrap();
+        assert_eq!(simulation.probabilities.len(), 5);
+        let (team, _) = &simulation.probabilities[4];
+        assert_eq!(team, "E");
+        for (_, probabilities) in &simulation.probabilities {
+            assert_eq!(probabilities.len(), 5);
+            let total: f64 = probabilities.iter().sum();
+            assert!((total - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn simulation_without_open_fixtures_is_certain() {
+        let simulation = table().simulate(&[], 10, 7).unwrap();
+        let (team, probabilities) = &simulation.probabilities[0];
+        assert_eq!(team, "A");
+        assert_eq!(probabilities[0], 1.0);
+    }
+
+    #[test]
+    fn simulation_keeps_adjustments() {
+        let deduction = Adjustment {
+            team: String::from("A"),
+            points: -3,
+            reason: String::from("licence breach"),
+            from_day: 1,
+        };
+        let mut table = table();
+        table.adjustments =
+            apply_adjustments(&mut table.rows, &[deduction], &Aliases::default(), 1).unwrap();
+        let simulation = table.simulate(&[], 10, 7).unwrap();
+        let (team, probabilities) = &simulation.probabilities[0];
+        assert_eq!(team, "C");
+        assert_eq!(probabilities[0], 1.0);
+        let (team, probabilities) = &simulation.probabilities[1];
+        assert_eq!(team, "A");
+        assert_eq!(probabilities[1], 1.0);
+    }
+}
@@ -1,17 +1,40 @@
-use crate::parsing::MatchResult;
+use crate::parsing::{Decision, MatchResult};
+use crate::rules::PointsScheme;
 use std::cmp::{Ord, Ordering};
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct TableRow {
-    pub rank: u8,
+    pub rank: usize,
     pub team: String,
-    pub points: u8,
-    pub wins: u8,
-    pub defeats: u8,
-    pub ties: u8,
-    pub goals_scored: u8,
-    pub goals_conceded: u8,
-    pub goals_diff: i8,
+    pub points: i32,
+    pub wins: u32,
+    pub defeats: u32,
+    pub ties: u32,
+    pub goals_scored: u32,
+    pub goals_conceded: u32,
+    pub goals_diff: i32,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum RowError {
+    TeamMismatch { left: String, right: String },
+    Overflow { team: String, field: &'static str },
+}
+
+impl Error for RowError {}
+
+impl Display for RowError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            RowError::TeamMismatch { left: l, right: r } => {
+                write!(f, "cannot combine rows of teams '{l}' and '{r}'")
+            }
+            RowError::Overflow { team: t, field: c } => write!(f, "{c} of team '{t}' overflows"),
+        }
+    }
 }
 
 impl PartialOrd for TableRow {
@@ -46,6 +69,10 @@ impl TableRow {
     }
 
     pub fn from(result: MatchResult) -> (Self, Self) {
+        Self::from_with_points(result, &PointsScheme::default())
+    }
+
+    pub fn from_with_points(result: MatchResult, scheme: &PointsScheme) -> (Self, Self) {
         let mut home = TableRow {
             rank: 0,
             team: result.home_team,
@@ -53,9 +80,9 @@ impl TableRow {
             wins: 0,
             defeats: 0,
             ties: 0,
-            goals_scored: result.home_goals,
-            goals_conceded: result.away_goals,
-            goals_diff: result.home_goals as i8 - result.away_goals as i8,
+            goals_scored: result.home_goals as u32,
+            goals_conceded: result.away_goals as u32,
+            goals_diff: result.home_goals as i32 - result.away_goals as i32,
         };
         let mut away = TableRow {
             rank: 0,
@@ -64,50 +91,79 @@ impl TableRow {
             wins: 0,
             defeats: 0,
             ties: 0,
-            goals_scored: result.away_goals,
-            goals_conceded: result.home_goals,
-            goals_diff: result.away_goals as i8 - result.home_goals as i8,
+            goals_scored: result.away_goals as u32,
+            goals_conceded: result.home_goals as u32,
+            goals_diff: result.away_goals as i32 - result.home_goals as i32,
         };
-        match result.home_goals.cmp(&result.away_goals) {
-            Ordering::Less => {
+        let (winner, loser) = scheme.for_decision(&result.decision);
+        match (result.home_goals.cmp(&result.away_goals), result.decision) {
+            (_, Decision::Penalties { home: h, away: a }) => {
+                home.ties = 1;
+                away.ties = 1;
+                (home.points, away.points) = match h > a {
+                    true => (winner, loser),
+                    false => (loser, winner),
+                };
+            }
+            (Ordering::Less, _) => {
                 home.defeats = 1;
                 away.wins = 1;
-                away.points = 3;
+                home.points = loser;
+                away.points = winner;
             }
-            Ordering::Greater => {
+            (Ordering::Greater, _) => {
                 home.wins = 1;
                 away.defeats = 1;
-                home.points = 3;
+                home.points = winner;
+                away.points = loser;
             }
-            Ordering::Equal => {
+            (Ordering::Equal, _) => {
                 home.ties = 1;
                 away.ties = 1;
-                home.points = 1;
-                away.points = 1;
+                home.points = scheme.tie;
+                away.points = scheme.tie;
             }
         }
         (home, away)
     }
 
-    pub fn combine(self, other: Self) -> Result<Self, String> {
+    pub fn combine(self, other: Self) -> Result<Self, RowError> {
         if self.team != other.team {
-            Err(format!(
-                "cannot combine rows of teams '{}' and '{}'",
-                self.team, other.team
-            ))
-        } else {
-            Ok(TableRow {
-                rank: 0,
-                team: self.team.clone(),
-                points: self.points + other.points,
-                wins: self.wins + other.wins,
-                defeats: self.defeats + other.defeats,
-                ties: self.ties + other.ties,
-                goals_scored: self.goals_scored + other.goals_scored,
-                goals_conceded: self.goals_conceded + other.goals_conceded,
-                goals_diff: self.goals_diff + other.goals_diff,
-            })
+            return Err(RowError::TeamMismatch {
+                left: self.team,
+                right: other.team,
+            });
         }
+        let overflow = |field| RowError::Overflow {
+            team: self.team.clone(),
+            field,
+        };
+        Ok(TableRow {
+            rank: 0,
+            team: self.team.clone(),
+            points: self
+                .points
+                .checked_add(other.points)
+                .ok_or(overflow("points"))?,
+            wins: self.wins.checked_add(other.wins).ok_or(overflow("wins"))?,
+            defeats: self
+                .defeats
+                .checked_add(other.defeats)
+                .ok_or(overflow("defeats"))?,
+            ties: self.ties.checked_add(other.ties).ok_or(overflow("ties"))?,
+            goals_scored: self
+                .goals_scored
+                .checked_add(other.goals_scored)
+                .ok_or(overflow("goals_scored"))?,
+            goals_conceded: self
+                .goals_conceded
+                .checked_add(other.goals_conceded)
+                .ok_or(overflow("goals_conceded"))?,
+            goals_diff: self
+                .goals_diff
+                .checked_add(other.goals_diff)
+                .ok_or(overflow("goals_diff"))?,
+        })
     }
 }
 
@@ -122,6 +178,9 @@ mod tests {
             away_team: String::from("COBOL FC 1958"),
             home_goals: 3,
             away_goals: 2,
+            decision: Decision::Regular,
+            goals: Vec::new(),
+            date: None,
         };
         let expected_home = TableRow {
             rank: 0,
@@ -157,6 +216,9 @@ mod tests {
             away_team: String::from("COBOL FC 1958"),
             home_goals: 2,
             away_goals: 4,
+            decision: Decision::Regular,
+            goals: Vec::new(),
+            date: None,
         };
         let expected_home = TableRow {
             rank: 0,
@@ -192,6 +254,9 @@ mod tests {
             away_team: String::from("COBOL FC 1958"),
             home_goals: 3,
             away_goals: 3,
+            decision: Decision::Regular,
+            goals: Vec::new(),
+            date: None,
         };
         let expected_home = TableRow {
             rank: 0,
@@ -220,6 +285,61 @@ mod tests {
         assert_eq!(actual_away, expected_away);
     }
 
+    #[test]
+    fn rows_from_two_point_win() {
+        let result = MatchResult {
+            home_team: String::from("FC Rustaceans"),
+            away_team: String::from("COBOL FC 1958"),
+            home_goals: 1,
+            away_goals: 0,
+            decision: Decision::Regular,
+            goals: Vec::new(),
+            date: None,
+        };
+        let scheme = PointsScheme {
+            win: 2,
+            tie: 1,
+            defeat: 0,
+            ..PointsScheme::default()
+        };
+        let (home, away) = TableRow::from_with_points(result, &scheme);
+        assert_eq!(home.points, 2);
+        assert_eq!(away.points, 0);
+    }
+
+    #[test]
+    fn rows_from_decided_matches() {
+        let scheme = PointsScheme {
+            extra_time_win: 2,
+            extra_time_defeat: 1,
+            penalty_win: 2,
+            penalty_defeat: 1,
+            ..PointsScheme::default()
+        };
+        let result = |home_goals, away_goals, decision| MatchResult {
+            home_team: String::from("FC Rustaceans"),
+            away_team: String::from("COBOL FC 1958"),
+            home_goals,
+            away_goals,
+            decision,
+            goals: Vec::new(),
+            date: None,
+        };
+
+        let (home, away) = TableRow::from_with_points(result(2, 3, Decision::ExtraTime), &scheme);
+        assert_eq!((home.defeats, home.points), (1, 1));
+        assert_eq!((away.wins, away.points), (1, 2));
+
+        let penalties = Decision::Penalties { home: 5, away: 4 };
+        let (home, away) = TableRow::from_with_points(result(1, 1, penalties), &scheme);
+        assert_eq!((home.ties, home.points), (1, 2));
+        assert_eq!((away.ties, away.points), (1, 1));
+
+        let (home, away) = TableRow::from_with_points(result(0, 3, Decision::Forfeit), &scheme);
+        assert_eq!((home.defeats, home.points, home.goals_conceded), (1, 0, 3));
+        assert_eq!((away.wins, away.points, away.goals_scored), (1, 3, 3));
+    }
+
     #[test]
     fn combine_rows_of_different_teams() {
         let team_a_first = TableRow {
@@ -287,6 +407,19 @@ mod tests {
         assert_eq!(actual, Ok(expected));
     }
 
+    #[test]
+    fn combine_rows_overflowing() {
+        let mut team_a_first = TableRow::new("FC Rustaceans");
+        let mut team_a_second = TableRow::new("FC Rustaceans");
+        team_a_first.goals_scored = u32::MAX;
+        team_a_second.goals_scored = 1;
+        let expected = RowError::Overflow {
+            team: String::from("FC Rustaceans"),
+            field: "goals_scored",
+        };
+        assert_eq!(team_a_first.combine(team_a_second), Err(expected));
+    }
+
     #[test]
     fn cmp_rows_by_points() {
         let mut a = TableRow::new("A");
@@ -356,9 +489,9 @@ mod tests {
         d.wins = 7;
         e.wins = 7;
 
-        let mut rows = vec![d, c, e, b, a];
+        let mut rows = [d, c, e, b, a];
         rows.sort();
-        assert_eq!(rows.get(0).unwrap().team, "A");
+        assert_eq!(rows.first().unwrap().team, "A");
         assert_eq!(rows.get(1).unwrap().team, "B");
         assert_eq!(rows.get(2).unwrap().team, "C");
         assert_eq!(rows.get(3).unwrap().team, "D");
@@ -0,0 +1,103 @@
+use crate::{assemble_table, read_file, select_files, Failure, Options, Parsed, Table};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+type Stamp = (Option<SystemTime>, u64);
+
+pub struct Watcher {
+    dir: PathBuf,
+    day: Option<usize>,
+    options: Options,
+    files: Vec<(usize, PathBuf)>,
+    cache: HashMap<PathBuf, (Stamp, Parsed)>,
+    reparsed: Vec<PathBuf>,
+}
+
+impl Watcher {
+    pub fn new(dir: &Path, day: Option<usize>, options: &Options) -> Watcher {
+        Watcher {
+            dir: dir.to_path_buf(),
+            day,
+            options: options.clone(),
+            files: Vec::new(),
+            cache: HashMap::new(),
+            reparsed: Vec::new(),
+        }
+    }
+
+    pub fn poll(&mut self) -> Result<Option<Table>, Failure> {
+        let files = select_files(&self.dir, self.day, &self.options)?;
+        self.reparsed.clear();
+        for (_, file) in &files {
+            let meta = fs::metadata(file)
+                .map_err(|e| Failure::Io(format!("stat {}: {e}", file.display())))?;
+            let stamp = (meta.modified().ok(), meta.len());
+            match self.cache.get(file) {
+                Some((cached, _)) if *cached == stamp => {}
+                _ => {
+                    let parsed = read_file(file, false)?;
+                    self.cache.insert(file.clone(), (stamp, parsed));
+                    self.reparsed.push(file.clone());
+                }
+            }
+        }
+        self.cache
+            .retain(|f, _| files.iter().any(|(_, file)| file == f));
+        if self.reparsed.is_empty() && files == self.files {
+            return Ok(None);
+        }
+        self.files = files;
+        let mut parsed = Parsed::default();
+        for (_, file) in &self.files {
+            if let Some((_, cached)) = self.cache.get(file) {
+                parsed.extend(cached.clone());
+            }
+        }
+        let mut names = self.options.aliases.clone();
+        let parsed = parsed.finish(self.options.lenient, &mut names)?;
+        let day = self.files.last().map_or(0, |(d, _)| *d);
+        assemble_table(
+            parsed.within(&self.options.window),
+            names,
+            &self.options,
+            day,
+        )
+        .map(Some)
+    }
+
+    pub fn reparsed(&self) -> &[PathBuf] {
+        &self.reparsed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+
+    #[test]
+    fn reparse_changed_files_only() {
+        let test_dir = temp_dir("soccer-table-test-watch");
+        let path = test_dir.as_path();
+        fs::write(path.join("01.txt"), "A 1:0 B\n").unwrap();
+        fs::write(path.join("02.txt"), "B 2:2 C\n").unwrap();
+
+        let mut watcher = Watcher::new(path, None, &Options::default());
+        let table = watcher.poll().unwrap().unwrap();
+        assert_eq!(watcher.reparsed().len(), 2);
+        assert_eq!(table.ranked().unwrap()[0].team, "A");
+        assert!(watcher.poll().unwrap().is_none());
+
+        fs::write(path.join("02.txt"), "B 2:2 C\nC 3:0 A\n").unwrap();
+        let table = watcher.poll().unwrap().unwrap();
+        assert_eq!(watcher.reparsed(), [path.join("02.txt")]);
+        assert_eq!(table.ranked().unwrap()[0].team, "C");
+
+        fs::remove_file(path.join("02.txt")).unwrap();
+        let table = watcher.poll().unwrap().unwrap();
+        assert!(watcher.reparsed().is_empty());
+        assert_eq!(table.ranked().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,116 @@
+use std::fmt::{Display, Error, Formatter};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    fn days_in_month(year: u16, month: u8) -> u8 {
+        match month {
+            2 if year.is_multiple_of(4)
+                && (!year.is_multiple_of(100) || year.is_multiple_of(400)) =>
+            {
+                29
+            }
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split('-').collect();
+        let [year, month, day] = fields.as_slice() else {
+            return Err(format!("expected a date as YYYY-MM-DD, got '{s}'"));
+        };
+        if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+            return Err(format!("expected a date as YYYY-MM-DD, got '{s}'"));
+        }
+        let number = |val: &str| {
+            val.parse::<u16>()
+                .map_err(|e| format!("parse date '{s}': {e}"))
+        };
+        let (year, month, day) = (number(year)?, number(month)? as u8, number(day)? as u8);
+        if !(1..=12).contains(&month) || day == 0 || day > Date::days_in_month(year, month) {
+            return Err(format!("no such date '{s}'"));
+        }
+        Ok(Date { year, month, day })
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Selects the matches taken into account by matchday and by date, all bounds inclusive.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Window {
+    pub from_day: Option<usize>,
+    pub to_day: Option<usize>,
+    pub from_date: Option<Date>,
+    pub to_date: Option<Date>,
+}
+
+impl Window {
+    pub fn is_unbounded(&self) -> bool {
+        *self == Window::default()
+    }
+
+    pub fn contains_day(&self, day: usize) -> bool {
+        self.from_day.is_none_or(|from| day >= from) && self.to_day.is_none_or(|to| day <= to)
+    }
+
+    /// Undated matches only fall within a window without date bounds.
+    pub fn contains_date(&self, date: Option<Date>) -> bool {
+        match date {
+            Some(date) => {
+                self.from_date.is_none_or(|from| date >= from)
+                    && self.to_date.is_none_or(|to| date <= to)
+            }
+            None => self.from_date.is_none() && self.to_date.is_none(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_dates() {
+        let date: Date = "2024-02-29".parse().unwrap();
+        assert_eq!((date.year, date.month, date.day), (2024, 2, 29));
+        assert_eq!(date.to_string(), "2024-02-29");
+        assert!("2023-02-29".parse::<Date>().is_err());
+        assert!("2024-13-01".parse::<Date>().is_err());
+        assert!("2024-8-17".parse::<Date>().is_err());
+        assert!("17.08.2024".parse::<Date>().is_err());
+    }
+
+    #[test]
+    fn window_bounds() {
+        let window = Window {
+            from_day: Some(3),
+            to_day: Some(5),
+            from_date: "2024-08-01".parse().ok(),
+            to_date: None,
+        };
+        assert!(!window.contains_day(2));
+        assert!(window.contains_day(3) && window.contains_day(5));
+        assert!(!window.contains_day(6));
+        assert!(window.contains_date("2024-08-01".parse().ok()));
+        assert!(!window.contains_date("2024-07-31".parse().ok()));
+        assert!(!window.contains_date(None));
+        assert!(Window::default().contains_date(None));
+    }
+}